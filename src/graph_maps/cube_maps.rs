@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use crate::graph_maps::{GraphMap, GraphMapError, VertGraphMap};
-use crate::graphs::cube::{CubeGraph, Newable};
+use crate::graphs::cube::CubeGraph;
 use crate::graphs::UGraph;
 use crate::prelude::*;
 
@@ -11,25 +11,52 @@ where
     M: GraphMap<CubeGraph<D>, V>,
 {
     map: M,
-    degenerate_indices: Vec<bool>, // -> should just be a u32 bitmask
+    // Bit i is set when the map is constant along coordinate i
+    degenerate_mask: u32,
     _marker: std::marker::PhantomData<(D, V)>,
 }
 
 // Inclusion / Forgetful functor
-impl<V: UGraph, M: GraphMap<CubeGraph<u32>, V>> From<M> for CubeMap<u32, V, M> {
+impl<D: Dim, V: UGraph, M: CubeDegeneracy<D, V>> From<M> for CubeMap<D, V, M> {
     fn from(value: M) -> Self {
-        let degenerate_indices = (0..value.domain().dim().size() as usize)
-            .map(|i| d(&value, i as u32, false).vert_maps == d(&value, i as u32, true).vert_maps)
-            .collect();
+        let degenerate_mask = value.degenerate_mask();
 
         Self {
             map: value,
-            degenerate_indices,
+            degenerate_mask,
             _marker: std::marker::PhantomData,
         }
     }
 }
 
+/// Cube maps that can tell along which coordinates they are constant, reading their
+/// images in place.
+pub trait CubeDegeneracy<D: Dim, V: UGraph>: GraphMap<CubeGraph<D>, V> {
+    /// Bitmask whose i-th bit is set when the map does not depend on coordinate i,
+    /// i.e. `map(v) == map(v ^ (1 << i))` for every vertex `v`.
+    ///
+    /// Makes one pass over the images per coordinate, stopping at the first mismatch.
+    fn degenerate_mask(&self) -> u32;
+}
+
+impl<D: Dim, V: UGraph> CubeDegeneracy<D, V> for VertGraphMap<'_, '_, CubeGraph<D>, V> {
+    fn degenerate_mask(&self) -> u32 {
+        let dim = self.domain.dim().size();
+        debug_assert!(dim < 32);
+        let mut mask = 0u32;
+        for i in 0..dim {
+            let bit = 1usize << i;
+            let degenerate = (0..self.vert_maps.len())
+                .filter(|v| v & bit == 0)
+                .all(|v| self.vert_maps[v] == self.vert_maps[v | bit]);
+            if degenerate {
+                mask |= 1 << i;
+            }
+        }
+        mask
+    }
+}
+
 fn put_bit(x: u32, pos: u32, value: u32) -> u32 {
     debug_assert!(pos < 32);
     debug_assert!(value == 0 || value == 1);
@@ -42,27 +69,67 @@ fn put_bit(x: u32, pos: u32, value: u32) -> u32 {
 
     lower | (value << pos) | upper_shifted
 }
-// TODO: Implement d_i as stack graph map
-fn d<V: UGraph>(
-    map: &impl GraphMap<CubeGraph<u32>, V>,
-    i: u32,
-    sign: bool,
-) -> VertGraphMap<'_, '_, CubeGraph<u32>, V> {
-    let dim = map.domain().dim().size();
-    assert!(dim != 0u32);
-    debug_assert!(i < dim);
-    let new_dim = dim.checked_sub(1).unwrap();
-    let num_verts = 2_u32.checked_pow(new_dim).unwrap();
-    let vert_maps = (0..num_verts).map(|v| map.map(put_bit(v, i, if sign { 1 } else { 0 })));
-    unsafe {
-        VertGraphMap::new_unchecked(
-            Cow::Owned(CubeGraph::new(new_dim)),
-            Cow::Borrowed(map.codomain()),
-            Cow::Owned(vert_maps.collect()),
-        )
+/// Cube maps whose faces and gluings stay in the same representation.
+///
+/// For `CubeGraph<u32>` domains `Face` and `Glued` are `Self`, for `CubeGraph<Const<N>>`
+/// they are typed by [StepDim].
+pub trait CubeFaces<D: StepDim, V: UGraph>: CubeDegeneracy<D, V> {
+    type Face: GraphMap<CubeGraph<D::Prev>, V>;
+    type Glued: GraphMap<CubeGraph<D::Next>, V>;
+
+    /// The face map `d_i^sign`, restricting the map to the sub-cube where coordinate `i` is `sign`.
+    fn face(&self, i: u32, sign: bool) -> Self::Face;
+
+    /// Glues `self` and `other` along a new last coordinate, so that
+    /// `glue(other).face(dim, false) == self` and `glue(other).face(dim, true) == other`.
+    ///
+    /// # Safety
+    ///
+    /// `self` and `other` must have the same domain and codomain, and `self.map(v)` must
+    /// be adjacent or equal to `other.map(v)` for every vertex `v`. [CubeFaces::can_glue]
+    /// must hold, otherwise a packed representation overflows.
+    unsafe fn glue(&self, other: &Self) -> Self::Glued;
+
+    /// Whether the representation can hold maps on the cube one dimension up.
+    fn can_glue(&self) -> bool {
+        true
+    }
+}
+
+impl<'u, 'v, D: StepDim, V: UGraph> CubeFaces<D, V> for VertGraphMap<'u, 'v, CubeGraph<D>, V> {
+    type Face = VertGraphMap<'u, 'v, CubeGraph<D::Prev>, V>;
+    type Glued = VertGraphMap<'u, 'v, CubeGraph<D::Next>, V>;
+
+    fn face(&self, i: u32, sign: bool) -> Self::Face {
+        let dim = self.domain.dim().size();
+        assert!(dim != 0u32);
+        debug_assert!(i < dim);
+        let vert_maps = (0..2_u32.pow(dim - 1))
+            .map(|v| self.vert_maps[put_bit(v, i, sign as u32) as usize])
+            .collect();
+        VertGraphMap {
+            domain: Cow::Owned(self.domain.prev()),
+            codomain: self.codomain.clone(),
+            vert_maps,
+        }
+    }
+
+    unsafe fn glue(&self, other: &Self) -> Self::Glued {
+        debug_assert!(self.domain == other.domain);
+        let mut vert_maps = Vec::with_capacity(2 * self.vert_maps.len());
+        vert_maps.extend_from_slice(&self.vert_maps);
+        vert_maps.extend_from_slice(&other.vert_maps);
+        VertGraphMap {
+            domain: Cow::Owned(self.domain.next()),
+            codomain: self.codomain.clone(),
+            vert_maps,
+        }
     }
 }
 
+/// The cube maps produced by gluing two `CubeMap<D, V, M>`s.
+pub type GluedCubeMap<D, V, M> = CubeMap<<D as StepDim>::Next, V, <M as CubeFaces<D, V>>::Glued>;
+
 // impl<D: Dim, V: UGraph, M: GraphMap<CubeGraph<D>, V>> From<CubeMap<D, V, M>> for M {
 //     fn from(value: CubeMap<D, V, M>) -> Self {
 //         value.map
@@ -77,21 +144,45 @@ where
     pub fn into_inner(self) -> M {
         self.map
     }
+
+    pub fn map(&self) -> &M {
+        &self.map
+    }
+
+    pub fn degenerate_mask(&self) -> u32 {
+        self.degenerate_mask
+    }
+
+    pub fn is_degenerate(&self) -> bool {
+        self.degenerate_mask != 0
+    }
 }
 
-impl<V: UGraph, M: GraphMap<CubeGraph<u32>, V>> CubeMap<u32, V, M> {
+impl<D: Dim, V: UGraph, M: GraphMap<CubeGraph<D>, V>> CubeMap<D, V, M> {
     pub fn dim(&self) -> u32 {
-        self.map.domain().dim()
+        self.map.domain().dim().size()
     }
+}
 
-    pub fn try_combine<'w>(
+impl<D: StepDim, V: UGraph, M: CubeFaces<D, V>> CubeMap<D, V, M> {
+    /// Glues `self` and `other` along a new last coordinate, returning both
+    /// orderings `(self, other)` and `(other, self)`.
+    ///
+    /// `is_same` should be set when `other` is `self`, so the glued map is marked
+    /// degenerate along the new coordinate.
+    #[allow(clippy::type_complexity)]
+    pub fn try_combine(
         &self,
-        other: &CubeMap<u32, V, M>,
+        other: &CubeMap<D, V, M>,
         is_same: bool,
-    ) -> Result<(CubeMap<u32, V, M>, CubeMap<u32, V, M>), GraphMapError> {
+    ) -> Result<(GluedCubeMap<D, V, M>, GluedCubeMap<D, V, M>), GraphMapError> {
         use GraphMapError as E;
         assert!(self.map.codomain() == other.map.codomain());
+        let dim = self.dim();
         let n = self.map.domain().n();
+        if !self.map.can_glue() {
+            return Err(E::TooLarge(2 * n, self.map.codomain().n()));
+        }
         for (i, (x, y)) in self
             .map
             .mapped_vertices()
@@ -103,51 +194,31 @@ impl<V: UGraph, M: GraphMap<CubeGraph<u32>, V>> CubeMap<u32, V, M> {
             }
         }
 
-        let combined_verts = self
-            .map
-            .mapped_vertices()
-            .chain(other.map.mapped_vertices());
-        let combined_verts_2 = other
-            .map
-            .mapped_vertices()
-            .chain(self.map.mapped_vertices());
-
-        let map = unsafe {
-            self.map
-                .change_domain(CubeGraph::new(n + 1), combined_verts)
-        };
-        let map2 = unsafe {
-            other
-                .map
-                .change_domain(CubeGraph::new(n + 1), combined_verts_2)
-        };
-        let degenerate_indices_iter = self
-            .degenerate_indices
-            .iter()
-            .zip(other.degenerate_indices.iter())
-            .map(|(a, b)| *a && *b);
-        let mut degenerate_indices = Vec::with_capacity((n + 1) as usize);
-        degenerate_indices.extend(degenerate_indices_iter);
-        degenerate_indices.push(is_same);
+        // Safety: the size and the edges between the two halves were checked above
+        let map = unsafe { self.map.glue(&other.map) };
+        let map2 = unsafe { other.map.glue(&self.map) };
+        // The glued map is constant along an old coordinate iff both halves are
+        let degenerate_mask =
+            (self.degenerate_mask & other.degenerate_mask) | ((is_same as u32) << dim);
 
         Ok((
-            Self {
+            CubeMap {
                 map,
-                degenerate_indices: degenerate_indices.clone(),
+                degenerate_mask,
                 _marker: std::marker::PhantomData,
             },
-            Self {
+            CubeMap {
                 map: map2,
-                degenerate_indices,
+                degenerate_mask,
                 _marker: std::marker::PhantomData,
             },
         ))
     }
 }
 
-pub fn combined_cube_maps<V: UGraph, M: GraphMap<CubeGraph<u32>, V>>(
-    maps: &[CubeMap<u32, V, M>],
-) -> Vec<CubeMap<u32, V, M>> {
+pub fn combined_cube_maps<D: StepDim, V: UGraph, M: CubeFaces<D, V>>(
+    maps: &[CubeMap<D, V, M>],
+) -> Vec<GluedCubeMap<D, V, M>> {
     let mut combined_maps = Vec::new();
     let len = maps.len();
     for i in 0..len {
@@ -211,6 +282,7 @@ pub fn get_valid_graph_map<'u, 'v, U: UGraph, V: UGraph>(
 #[cfg(test)]
 mod tests {
     use crate::graph_maps::generate_maps_naive;
+    use crate::graphs::cube::Newable;
 
     use super::*;
 
@@ -282,8 +354,8 @@ mod tests {
             let target = extras::greene_sphere();
 
             let map = get_valid_graph_map(&source, &target, u.arbitrary()?);
-            let dn_map_pos = CubeMap::from(d(&map, dim - 1, true));
-            let dn_map_neg = CubeMap::from(d(&map, dim - 1, false));
+            let dn_map_pos = CubeMap::from(map.face(dim - 1, true));
+            let dn_map_neg = CubeMap::from(map.face(dim - 1, false));
             let recombined_map = dn_map_neg.try_combine(&dn_map_pos, false);
 
             if recombined_map.is_err() {
@@ -306,6 +378,95 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_degenerate_mask_matches_faces() {
+        use crate::graph_maps::stack_map::StackGraphMap;
+        use arbtest::arbtest;
+        arbtest(|u| {
+            let dim = 3;
+            let source = CubeGraph::new(dim);
+            let target = extras::c_n_graph(4);
+
+            let map = get_valid_graph_map(&source, &target, u.arbitrary()?);
+            let expected = (0..dim)
+                .filter(|&i| map.face(i, false).vert_maps == map.face(i, true).vert_maps)
+                .fold(0u32, |mask, i| mask | (1 << i));
+
+            let stack_map: StackGraphMap<'_, '_, _, _, u64> = (&map).into();
+            assert_eq!(map.degenerate_mask(), expected);
+            assert_eq!(stack_map.degenerate_mask(), expected);
+            assert_eq!(CubeMap::from(stack_map).degenerate_mask(), expected);
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_combined_degenerate_mask() {
+        let source = CubeGraph::new(1);
+        let target = extras::c_n_graph(5);
+        let (maps, _) = generate_maps_naive(&source, &target);
+        let maps = maps.into_iter().map(CubeMap::from).collect::<Vec<_>>();
+
+        let combined = combined_cube_maps(&maps);
+        let num_degenerate = combined.iter().filter(|m| m.is_degenerate()).count();
+        for map in combined {
+            let mask = map.degenerate_mask();
+            assert_eq!(map.dim(), 2);
+            assert_eq!(mask, CubeMap::from(map.into_inner()).degenerate_mask());
+        }
+        // Constant maps are degenerate in both directions, and each edge of C_5
+        // (in either orientation) gives one map degenerate in each direction.
+        assert_eq!(num_degenerate, 5 + 2 * 2 * 5);
+    }
+
+    #[test]
+    fn test_const_dim_combined() {
+        let target = extras::c_n_graph(5);
+        let const_cube = CubeGraph::<Const<1>>::default();
+        let runtime_cube = CubeGraph::new(1);
+
+        let const_maps = generate_maps_naive(&const_cube, &target)
+            .0
+            .into_iter()
+            .map(CubeMap::from)
+            .collect::<Vec<_>>();
+        let runtime_maps = generate_maps_naive(&runtime_cube, &target)
+            .0
+            .into_iter()
+            .map(CubeMap::from)
+            .collect::<Vec<_>>();
+
+        let const_combined = combined_cube_maps(&const_maps);
+        let runtime_combined = combined_cube_maps(&runtime_maps);
+        assert_eq!(const_combined.len(), runtime_combined.len());
+        for (c, r) in const_combined.iter().zip(runtime_combined.iter()) {
+            assert_eq!(c.dim(), 2);
+            assert_eq!(c.degenerate_mask(), r.degenerate_mask());
+            assert_eq!(c.map.vert_maps, r.map.vert_maps);
+            for i in 0..2 {
+                for sign in [false, true] {
+                    assert_eq!(c.map.face(i, sign).vert_maps, r.map.face(i, sign).vert_maps);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_const_dim_typed_faces() {
+        let source = CubeGraph::<Const<2>>::default();
+        let target = extras::greene_sphere();
+        let map = get_valid_graph_map(&source, &target, 0);
+
+        let face: VertGraphMap<'_, '_, CubeGraph<Const<1>>, _> = map.face(1, true);
+        let glued: VertGraphMap<'_, '_, CubeGraph<Const<3>>, _> = unsafe { map.glue(&map) };
+        assert_eq!(face.vert_maps, map.face(1, true).vert_maps);
+
+        let cube_map = CubeMap::from(glued);
+        assert_eq!(cube_map.dim(), 3);
+        assert_eq!(cube_map.degenerate_mask() & 0b100, 0b100);
+    }
+
     #[test]
     fn test_2cube_gsphere_combined() {
         let n = 2;
@@ -0,0 +1,104 @@
+use crate::graph_maps::GraphMap;
+use crate::prelude::*;
+
+/// The winding number of a map `C_n -> C_m` between cycles labelled as by
+/// [c_n_graph](crate::graphs::extras::c_n_graph): the total signed number of steps
+/// `f(i) -> f(i + 1)` around the codomain, divided by `m`.
+pub fn winding_number<U: UGraph, V: UGraph>(f: &impl GraphMap<U, V>) -> i32 {
+    let n = f.domain().n();
+    let m = f.codomain().n() as i32;
+    debug_assert!((0..n).all(|i| f.domain().is_edge(i, (i + 1) % n)));
+    let steps: i32 = (0..n)
+        .map(|i| {
+            let step = (f.map((i + 1) % n) as i32 - f.map(i) as i32).rem_euclid(m);
+            match step {
+                0 => 0,
+                1 => 1,
+                s if s == m - 1 => -1,
+                _ => panic!("f({i}) and f({}) are not adjacent", (i + 1) % n),
+            }
+        })
+        .sum();
+    debug_assert!(steps % m == 0);
+    steps / m
+}
+
+/// The A-homotopy class of a map `C_n -> C_m`. For `m >= 5` maps are classified by their
+/// winding number, while `C_3` and `C_4` are contractible so every map is in class 0.
+pub fn cycle_homotopy_class<U: UGraph, V: UGraph>(f: &impl GraphMap<U, V>) -> i32 {
+    if f.codomain().n() < 5 {
+        0
+    } else {
+        winding_number(f)
+    }
+}
+
+/// The classes of `[C_n, C_m]` as in [cycle_homotopy_class]: every winding number `w` with
+/// `|w| m <= n` when `m >= 5`, and a single class otherwise.
+pub fn cycle_homotopy_classes(n: u32, m: u32) -> Vec<i32> {
+    assert!(n >= 3 && m >= 3, "cycles have at least 3 vertices");
+    if m < 5 {
+        return vec![0];
+    }
+    let max = (n / m) as i32;
+    (-max..=max).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::hom_graph::HomGraph;
+    use crate::graphs::connected_components;
+    use crate::graphs::extras::c_n_graph;
+
+    #[test]
+    fn test_winding_number() {
+        let c5 = c_n_graph(5);
+        let c10 = c_n_graph(10);
+        let hom = HomGraph::new(c10, c5);
+        let wrap = hom
+            .maps()
+            .index_of(&[0, 1, 2, 3, 4, 0, 1, 2, 3, 4])
+            .unwrap();
+        let back = hom
+            .maps()
+            .index_of(&[0, 4, 3, 2, 1, 0, 4, 3, 2, 1])
+            .unwrap();
+        let once = hom
+            .maps()
+            .index_of(&[0, 1, 2, 3, 4, 0, 0, 0, 0, 0])
+            .unwrap();
+        let zigzag = hom
+            .maps()
+            .index_of(&[0, 1, 0, 4, 0, 1, 2, 1, 0, 0])
+            .unwrap();
+        assert_eq!(winding_number(&hom.map(wrap)), 2);
+        assert_eq!(winding_number(&hom.map(back)), -2);
+        assert_eq!(winding_number(&hom.map(once)), 1);
+        assert_eq!(winding_number(&hom.map(zigzag)), 0);
+    }
+
+    #[test]
+    fn test_classification_matches_components() {
+        for n in 3..=7 {
+            // Every map into C_3 is one step from every other, keep those Hom-graphs small
+            let min_m = if n > 5 { 4 } else { 3 };
+            for m in min_m..=7 {
+                let hom = HomGraph::new(c_n_graph(n), c_n_graph(m));
+                let components = connected_components(&hom);
+                let mut classes = components
+                    .iter()
+                    .map(|component| {
+                        let class = cycle_homotopy_class(&hom.map(component[0]));
+                        assert!(component
+                            .iter()
+                            .all(|&i| cycle_homotopy_class(&hom.map(i)) == class));
+                        class
+                    })
+                    .collect::<Vec<_>>();
+                classes.sort_unstable();
+                assert_eq!(classes, cycle_homotopy_classes(n, m), "[C_{n}, C_{m}]");
+            }
+        }
+    }
+}
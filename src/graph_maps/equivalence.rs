@@ -0,0 +1,155 @@
+use crate::graph_maps::hom_graph::{path_to_maps, search_homotopy};
+use crate::graph_maps::{compose, GraphMap, VertGraphMap};
+use crate::prelude::*;
+
+/// Homotopies witnessing that `f: G -> H` and `g: H -> G` are inverse A-homotopy
+/// equivalences.
+#[derive(Debug, Clone)]
+pub struct HomotopyEquivalence<'g, 'h, G: UGraph, H: UGraph> {
+    /// A homotopy from `g ∘ f` to `id_G`
+    pub domain_homotopy: Vec<VertGraphMap<'g, 'g, G, G>>,
+    /// A homotopy from `f ∘ g` to `id_H`
+    pub codomain_homotopy: Vec<VertGraphMap<'h, 'h, H, H>>,
+}
+
+/// The closest a composite came to the identity: a homotopy from the composite to the
+/// reachable map fixing the most vertices.
+#[derive(Debug, Clone)]
+pub struct NearestHomotopy<'a, U: UGraph> {
+    pub homotopy: Vec<VertGraphMap<'a, 'a, U, U>>,
+    /// Number of vertices fixed by the last map of `homotopy`
+    pub fixed_vertices: u32,
+}
+
+/// The side on which a candidate equivalence failed.
+#[derive(Debug, Clone)]
+pub enum EquivalenceFailure<'g, 'h, G: UGraph, H: UGraph> {
+    /// `g ∘ f` is not homotopic to `id_G`
+    Domain(NearestHomotopy<'g, G>),
+    /// `f ∘ g` is not homotopic to `id_H`
+    Codomain(NearestHomotopy<'h, H>),
+}
+
+/// Checks whether `f: G -> H` and `g: H -> G` are inverse A-homotopy equivalences, by
+/// searching the Hom-graphs for homotopies `g ∘ f ≃ id_G` and `f ∘ g ≃ id_H`.
+///
+/// The domain side is checked first; on failure the first failing side is reported together
+/// with the nearest homotopy attempt. Each search visits the whole homotopy class of the
+/// composite in the worst case.
+pub fn check_homotopy_equivalence<'g, 'h, G: UGraph, H: UGraph>(
+    f: &'g impl GraphMap<G, H>,
+    g: &'h impl GraphMap<H, G>,
+) -> Result<HomotopyEquivalence<'g, 'h, G, H>, EquivalenceFailure<'g, 'h, G, H>> {
+    assert!(f.domain() == g.codomain() && f.codomain() == g.domain());
+    let domain_homotopy =
+        homotopy_to_identity(f.domain(), &compose(f, g)).map_err(EquivalenceFailure::Domain)?;
+    let codomain_homotopy =
+        homotopy_to_identity(g.domain(), &compose(g, f)).map_err(EquivalenceFailure::Codomain)?;
+    Ok(HomotopyEquivalence {
+        domain_homotopy,
+        codomain_homotopy,
+    })
+}
+
+fn homotopy_to_identity<'a, U: UGraph>(
+    graph: &'a U,
+    composite: &impl GraphMap<U, U>,
+) -> Result<Vec<VertGraphMap<'a, 'a, U, U>>, NearestHomotopy<'a, U>> {
+    let identity = VertGraphMap::identity(graph);
+    let start = composite.mapped_vertices().collect();
+    match search_homotopy(graph, graph, start, &identity.vert_maps, &[]) {
+        Ok(path) => Ok(path_to_maps(graph, graph, path)),
+        Err(path) => {
+            let last = path.last().expect("path contains the composite");
+            let fixed_vertices = last
+                .iter()
+                .enumerate()
+                .filter(|&(u, &v)| u as u32 == v)
+                .count();
+            Err(NearestHomotopy {
+                homotopy: path_to_maps(graph, graph, path),
+                fixed_vertices: fixed_vertices as u32,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::hom_graph::is_one_step;
+    use crate::graphs::extras::{c_n_graph, grid_graph};
+    use crate::graphs::folds::fold_dominated;
+    use std::borrow::Cow;
+
+    fn point() -> CSRGraph {
+        CSRGraph::new(vec![0, 1], vec![0])
+    }
+
+    fn constant_map<'a, U: UGraph, V: UGraph>(
+        domain: &'a U,
+        codomain: &'a V,
+        v: u32,
+    ) -> VertGraphMap<'a, 'a, U, V> {
+        let mut workspace = vec![0; domain.n() as usize];
+        VertGraphMap::try_from(
+            Cow::Borrowed(domain),
+            Cow::Borrowed(codomain),
+            (0..domain.n()).map(|_| v),
+            &mut workspace,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_contractible_graphs() {
+        let pt = point();
+        for graph in [c_n_graph(4), grid_graph(3, 1)] {
+            let f = constant_map(&graph, &pt, 0);
+            let g = constant_map(&pt, &graph, 0);
+            let equivalence = check_homotopy_equivalence(&f, &g).unwrap();
+            let homotopy = &equivalence.domain_homotopy;
+            assert!(homotopy.windows(2).all(|w| is_one_step(&w[0], &w[1])));
+            assert!(homotopy.last().unwrap().mapped_vertices().eq(0..graph.n()));
+            assert_eq!(equivalence.codomain_homotopy.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_fold_is_equivalence() {
+        let mut adj: AdjMatrix = c_n_graph(5).into();
+        adj.iter_mut().for_each(|row| row.push(false));
+        adj.push(vec![false; 6]);
+        for a in [0, 1, 5] {
+            adj[a][5] = true;
+            adj[5][a] = true;
+        }
+        let graph = CSRGraph::try_from(adj).unwrap();
+        let (_, retraction, inclusion) = fold_dominated(&graph);
+        assert!(check_homotopy_equivalence(&retraction, &inclusion).is_ok());
+    }
+
+    #[test]
+    fn test_failing_sides() {
+        let c5 = c_n_graph(5);
+        let pt = point();
+        let f = constant_map(&c5, &pt, 0);
+        let g = constant_map(&pt, &c5, 0);
+        match check_homotopy_equivalence(&f, &g) {
+            Err(EquivalenceFailure::Domain(nearest)) => {
+                // Maps homotopic to a constant map have winding number 0 and fix at most
+                // three consecutive vertices
+                assert!(nearest.fixed_vertices < 5);
+                assert!(nearest
+                    .homotopy
+                    .windows(2)
+                    .all(|w| is_one_step(&w[0], &w[1])));
+            }
+            other => panic!("expected failure on the domain side, got {other:?}"),
+        }
+        match check_homotopy_equivalence(&g, &f) {
+            Err(EquivalenceFailure::Codomain(nearest)) => assert!(nearest.fixed_vertices < 5),
+            other => panic!("expected failure on the codomain side, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,624 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::graph_maps::{GraphMap, VertGraphMap};
+use crate::prelude::*;
+
+/// Whether `f` and `g` are one step apart in the Hom-graph, i.e. `f(u)` is adjacent or
+/// equal to `g(u)` for every vertex `u`.
+pub fn is_one_step<U: UGraph, V: UGraph>(f: &impl GraphMap<U, V>, g: &impl GraphMap<U, V>) -> bool {
+    let codomain = f.codomain();
+    f.mapped_vertices()
+        .zip(g.mapped_vertices())
+        .all(|(x, y)| codomain.is_edge(x, y))
+}
+
+/// The Hom-graph on `maps`: vertex `i` is `maps[i]`, and maps are adjacent when they are one
+/// step apart (see [is_one_step]).
+///
+/// All maps must share the same domain and codomain.
+pub fn hom_graph<U: UGraph, V: UGraph, M: GraphMap<U, V>>(maps: &[M]) -> CSRGraph {
+    let images = maps
+        .iter()
+        .map(|f| f.mapped_vertices().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let Some(first) = maps.first() else {
+        return CSRGraph::new(vec![0], vec![]);
+    };
+    let codomain = first.codomain();
+    debug_assert!(maps.iter().all(|f| f.codomain() == codomain));
+
+    // Maps one step from f send vertex 0 into the closed neighbourhood of f(0),
+    // so only those buckets need to be scanned.
+    let mut buckets: Vec<Vec<u32>> = vec![Vec::new(); codomain.n() as usize];
+    for (i, image) in images.iter().enumerate() {
+        if let Some(&v) = image.first() {
+            buckets[v as usize].push(i as u32);
+        }
+    }
+
+    let mut offsets = vec![0u32; maps.len() + 1];
+    let mut neighbor_list = Vec::new();
+    for (i, image) in images.iter().enumerate() {
+        let mut neighbors = match image.first() {
+            Some(&v) => codomain
+                .neighbors(v)
+                .flat_map(|w| buckets[w as usize].iter().copied())
+                .filter(|&j| {
+                    image
+                        .iter()
+                        .zip(images[j as usize].iter())
+                        .all(|(&x, &y)| codomain.is_edge(x, y))
+                })
+                .collect::<Vec<_>>(),
+            // There is a single map from the empty graph
+            None => (0..maps.len() as u32).collect(),
+        };
+        neighbors.sort_unstable();
+        neighbor_list.extend(neighbors);
+        offsets[i + 1] = neighbor_list.len() as u32;
+    }
+    CSRGraph::new(offsets, neighbor_list)
+}
+
+/// The A-homotopy classes `[U, V]` among `maps`, as the connected components of their
+/// [hom_graph]. Each class lists indices into `maps`.
+///
+/// `maps` should be closed under one-step homotopy (e.g. every graph map `U -> V` as
+/// produced by [generate_maps_naive](crate::graph_maps::generate_maps_naive)), otherwise
+/// two maps may be homotopic only through maps that are missing.
+pub fn homotopy_classes<U: UGraph, V: UGraph, M: GraphMap<U, V>>(maps: &[M]) -> Vec<Vec<u32>> {
+    connected_components(&hom_graph(maps))
+}
+
+/// All graph maps one step from the map with vertex images `images`, found by backtracking
+/// over the domain with `h(u)` in the closed neighbourhood of `images[u]`.
+pub fn one_step_maps<U: UGraph, V: UGraph>(
+    domain: &U,
+    codomain: &V,
+    images: &[u32],
+) -> Vec<Vec<u32>> {
+    one_step_maps_fixing(domain, codomain, images, &[])
+}
+
+/// Like [one_step_maps], but keeping the images of the `fixed` vertices unchanged.
+pub fn one_step_maps_fixing<U: UGraph, V: UGraph>(
+    domain: &U,
+    codomain: &V,
+    images: &[u32],
+    fixed: &[u32],
+) -> Vec<Vec<u32>> {
+    debug_assert!(images.len() == domain.n() as usize);
+    let mut choices = images
+        .iter()
+        .map(|&v| codomain.neighbors(v).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    for &u in fixed {
+        choices[u as usize] = vec![images[u as usize]];
+    }
+    generate_maps_within(domain, codomain, &choices)
+}
+
+/// All graph maps `h: domain -> codomain` with `h(u)` in `choices[u]` for every vertex `u`,
+/// found by backtracking over the vertices in order.
+pub fn generate_maps_within<U: UGraph, V: UGraph>(
+    domain: &U,
+    codomain: &V,
+    choices: &[Vec<u32>],
+) -> Vec<Vec<u32>> {
+    let n = domain.n() as usize;
+    debug_assert!(choices.len() == n);
+    // Earlier neighbours of each vertex, whose images constrain its own
+    let earlier = (0..n as u32)
+        .map(|u| domain.neighbors(u).filter(|&w| w < u).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let mut maps = Vec::new();
+    let mut current = vec![0u32; n];
+    // cursor[u] is the index into choices[u] to try next
+    let mut cursor = vec![0usize; n + 1];
+    let mut u = 0;
+    loop {
+        if u == n {
+            maps.push(current.clone());
+            if n == 0 {
+                return maps;
+            }
+            u -= 1;
+            continue;
+        }
+        let mut placed = false;
+        while cursor[u] < choices[u].len() {
+            let candidate = choices[u][cursor[u]];
+            cursor[u] += 1;
+            if earlier[u]
+                .iter()
+                .all(|&w| codomain.is_edge(current[w as usize], candidate))
+            {
+                current[u] = candidate;
+                placed = true;
+                break;
+            }
+        }
+        if placed {
+            u += 1;
+            cursor[u] = 0;
+        } else if u == 0 {
+            return maps;
+        } else {
+            u -= 1;
+        }
+    }
+}
+
+/// Decides whether `f` and `g` are A-homotopic by a breadth-first search of the Hom-graph
+/// from `f`.
+///
+/// On success returns a shortest homotopy `f = h_0, h_1, ..., h_k = g` with each map one step
+/// from the previous, equivalently a map `U □ I_k -> V`.
+pub fn find_homotopy<'a, U: UGraph, V: UGraph>(
+    f: &'a impl GraphMap<U, V>,
+    g: &impl GraphMap<U, V>,
+) -> Option<Vec<VertGraphMap<'a, 'a, U, V>>> {
+    find_homotopy_fixing(f, g, &[])
+}
+
+/// Like [find_homotopy], but only through maps that agree with `f` on the `fixed` vertices,
+/// e.g. the basepoint for based homotopies.
+pub fn find_homotopy_fixing<'a, U: UGraph, V: UGraph>(
+    f: &'a impl GraphMap<U, V>,
+    g: &impl GraphMap<U, V>,
+    fixed: &[u32],
+) -> Option<Vec<VertGraphMap<'a, 'a, U, V>>> {
+    let (domain, codomain) = (f.domain(), f.codomain());
+    assert!(domain == g.domain() && codomain == g.codomain());
+    let start = f.mapped_vertices().collect::<Vec<_>>();
+    let goal = g.mapped_vertices().collect::<Vec<_>>();
+    let path = search_homotopy(domain, codomain, start, &goal, fixed).ok()?;
+    Some(path_to_maps(domain, codomain, path))
+}
+
+/// Breadth-first search of the Hom-graph from `start` towards `goal`, through maps that agree
+/// with `start` on the `fixed` vertices.
+///
+/// Returns the images along a shortest homotopy, or if `goal` is unreachable, along a shortest
+/// homotopy to the reachable map agreeing with `goal` on the most vertices.
+pub(crate) fn search_homotopy<U: UGraph, V: UGraph>(
+    domain: &U,
+    codomain: &V,
+    start: Vec<u32>,
+    goal: &[u32],
+    fixed: &[u32],
+) -> Result<Vec<Vec<u32>>, Vec<Vec<u32>>> {
+    let agreement = |images: &[u32]| images.iter().zip(goal).filter(|(x, y)| x == y).count();
+
+    // Visited maps, with the index of the map they were reached from
+    let mut visited: HashMap<Vec<u32>, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut queue = vec![(start, 0usize)];
+    let mut head = 0;
+    let mut nearest = (agreement(&queue[0].0), 0);
+    while head < queue.len() {
+        if queue[head].0 == goal {
+            return Ok(trace_path(&queue, head));
+        }
+        let score = agreement(&queue[head].0);
+        if score > nearest.0 {
+            nearest = (score, head);
+        }
+        for next in one_step_maps_fixing(domain, codomain, &queue[head].0, fixed) {
+            if !visited.contains_key(&next) {
+                visited.insert(next.clone(), head);
+                queue.push((next, head));
+            }
+        }
+        head += 1;
+    }
+    Err(trace_path(&queue, nearest.1))
+}
+
+/// The images along the parent pointers of `queue` from the start to `queue[end]`.
+fn trace_path(queue: &[(Vec<u32>, usize)], end: usize) -> Vec<Vec<u32>> {
+    let mut path = Vec::new();
+    let mut i = end;
+    loop {
+        path.push(queue[i].0.clone());
+        if i == 0 {
+            break;
+        }
+        i = queue[i].1;
+    }
+    path.reverse();
+    path
+}
+
+/// Wraps images found by [search_homotopy] as maps borrowing `domain` and `codomain`.
+pub(crate) fn path_to_maps<'a, U: UGraph, V: UGraph>(
+    domain: &'a U,
+    codomain: &'a V,
+    path: Vec<Vec<u32>>,
+) -> Vec<VertGraphMap<'a, 'a, U, V>> {
+    path.into_iter()
+        // Safety: every map in the path was produced by one_step_maps_fixing
+        .map(|images| unsafe {
+            VertGraphMap::new_unchecked(
+                Cow::Borrowed(domain),
+                Cow::Borrowed(codomain),
+                Cow::Owned(images),
+            )
+        })
+        .collect()
+}
+
+/// An indexed set of graph maps, stored by their vertex images.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MapSet {
+    images: Vec<Vec<u32>>,
+    index: HashMap<Vec<u32>, u32>,
+}
+
+impl MapSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the map with vertex images `images`, returning its index.
+    pub fn insert(&mut self, images: Vec<u32>) -> u32 {
+        if let Some(&i) = self.index.get(&images) {
+            return i;
+        }
+        let i = self.images.len() as u32;
+        self.index.insert(images.clone(), i);
+        self.images.push(images);
+        i
+    }
+
+    pub fn index_of(&self, images: &[u32]) -> Option<u32> {
+        self.index.get(images).copied()
+    }
+
+    pub fn images(&self, i: u32) -> &[u32] {
+        &self.images[i as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.images.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &[u32]> {
+        self.images.iter().map(Vec::as_slice)
+    }
+}
+
+impl FromIterator<Vec<u32>> for MapSet {
+    fn from_iter<I: IntoIterator<Item = Vec<u32>>>(iter: I) -> Self {
+        let mut maps = Self::new();
+        for images in iter {
+            maps.insert(images);
+        }
+        maps
+    }
+}
+
+/// The Hom-graph `Hom(U, V)` as a graph in its own right: vertex `i` is the `i`-th map of a
+/// [MapSet], and maps are adjacent when they are one step apart.
+///
+/// Neighbours are found by enumerating [one_step_maps] and looking them up in the set, so
+/// a set that is not closed under one-step homotopy gives the induced subgraph on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HomGraph<U: UGraph, V: UGraph> {
+    domain: U,
+    codomain: V,
+    maps: MapSet,
+    /// Vertices of `domain` on which all the maps agree
+    fixed: Vec<u32>,
+}
+
+impl<U: UGraph, V: UGraph> HomGraph<U, V> {
+    /// The Hom-graph on every graph map `domain -> codomain`.
+    pub fn new(domain: U, codomain: V) -> Self {
+        let choices = vec![(0..codomain.n()).collect::<Vec<_>>(); domain.n() as usize];
+        let maps = generate_maps_within(&domain, &codomain, &choices)
+            .into_iter()
+            .collect();
+        Self::from_maps(domain, codomain, maps)
+    }
+
+    /// The Hom-graph on the given maps, which must all be graph maps `domain -> codomain`.
+    pub fn from_maps(domain: U, codomain: V, maps: MapSet) -> Self {
+        Self::from_maps_fixing(domain, codomain, maps, Vec::new())
+    }
+
+    /// Like [HomGraph::from_maps] for maps that all agree on the `fixed` vertices, such as
+    /// based maps. Neighbours are then only searched among maps keeping those images.
+    pub fn from_maps_fixing(domain: U, codomain: V, maps: MapSet, fixed: Vec<u32>) -> Self {
+        debug_assert!(maps.iter().all(|images| {
+            images.len() == domain.n() as usize
+                && (0..domain.n()).all(|u| {
+                    domain
+                        .neighbors(u)
+                        .all(|w| codomain.is_edge(images[u as usize], images[w as usize]))
+                })
+        }));
+        debug_assert!(maps.iter().all(|images| {
+            fixed
+                .iter()
+                .all(|&u| images[u as usize] == maps.images(0)[u as usize])
+        }));
+        Self {
+            domain,
+            codomain,
+            maps,
+            fixed,
+        }
+    }
+
+    pub fn domain(&self) -> &U {
+        &self.domain
+    }
+
+    pub fn codomain(&self) -> &V {
+        &self.codomain
+    }
+
+    pub fn maps(&self) -> &MapSet {
+        &self.maps
+    }
+
+    pub fn fixed(&self) -> &[u32] {
+        &self.fixed
+    }
+
+    pub fn into_maps(self) -> MapSet {
+        self.maps
+    }
+
+    /// The map vertex `i` stands for.
+    pub fn map(&self, i: u32) -> VertGraphMap<'_, '_, U, V> {
+        // Safety: the set only holds graph maps
+        unsafe {
+            VertGraphMap::new_unchecked(
+                Cow::Borrowed(&self.domain),
+                Cow::Borrowed(&self.codomain),
+                Cow::Borrowed(&self.maps.images[i as usize]),
+            )
+        }
+    }
+
+    /// The vertex standing for `f`, if it is in the set.
+    pub fn index_of(&self, f: &impl GraphMap<U, V>) -> Option<u32> {
+        self.maps.index_of(&f.mapped_vertices().collect::<Vec<_>>())
+    }
+}
+
+impl<U: UGraph, V: UGraph> GraphNeighbors for HomGraph<U, V> {
+    fn neighbors(&self, v: u32) -> impl Iterator<Item = u32> {
+        let images = self.maps.images(v);
+        let mut neighbors = one_step_maps_fixing(&self.domain, &self.codomain, images, &self.fixed)
+            .iter()
+            .filter_map(|images| self.maps.index_of(images))
+            .collect::<Vec<_>>();
+        neighbors.sort_unstable();
+        neighbors.into_iter()
+    }
+}
+
+impl<U: UGraph, V: UGraph> UGraph for HomGraph<U, V> {
+    fn n(&self) -> u32 {
+        self.maps.len() as u32
+    }
+
+    fn degree(&self, v: u32) -> u32 {
+        self.neighbors(v).count() as u32
+    }
+
+    fn is_edge<W: Into<u32>>(&self, a: W, b: W) -> bool {
+        let (a, b) = (self.maps.images(a.into()), self.maps.images(b.into()));
+        a.iter().zip(b).all(|(&x, &y)| self.codomain.is_edge(x, y))
+    }
+}
+
+/// Materializes the Hom-graph, enumerating the one-step maps of each map once.
+impl<U: UGraph, V: UGraph> From<&HomGraph<U, V>> for CSRGraph {
+    fn from(value: &HomGraph<U, V>) -> Self {
+        CSRGraph::from_graph(value)
+    }
+}
+
+impl<U: UGraph, V: UGraph> From<HomGraph<U, V>> for AdjMatrix {
+    fn from(value: HomGraph<U, V>) -> Self {
+        adjacency_matrix(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::stack_map::generate_maps_naive_stack;
+    use crate::graph_maps::{generate_maps_naive, GraphMap};
+    use crate::graphs::connected_components;
+    use crate::graphs::cube::{CubeGraph, Newable};
+    use crate::graphs::extras::{c_n_graph, greene_sphere};
+    use crate::homology::{betti_numbers, CubeComplex};
+
+    #[test]
+    fn test_hom_graph_is_reflexive_and_symmetric() {
+        let source = c_n_graph(4);
+        let target = c_n_graph(5);
+        let (maps, _) = generate_maps_naive(&source, &target);
+        let hom = hom_graph(&maps);
+        assert_eq!(hom.n() as usize, maps.len());
+
+        assert_eq!(assert_reflexive_symmetric(&hom), hom);
+
+        for i in 0..maps.len() {
+            for j in hom.neighbors(i as u32) {
+                assert!(is_one_step(&maps[i], &maps[j as usize]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_cycle_homotopy_classes() {
+        // C_4 is contractible
+        let c4 = c_n_graph(4);
+        let (maps, _) = generate_maps_naive(&c4, &c4);
+        assert_eq!(homotopy_classes(&maps).len(), 1);
+
+        // Maps C_5 -> C_5 are classified by winding number -1, 0 and 1; the rotations and
+        // reflections each form a class of 5 maps.
+        let c5 = c_n_graph(5);
+        let (maps, _) = generate_maps_naive(&c5, &c5);
+        let mut sizes = homotopy_classes(&maps)
+            .iter()
+            .map(|class| class.len())
+            .collect::<Vec<_>>();
+        sizes.sort_unstable();
+        assert_eq!(sizes.len(), 3);
+        assert_eq!(&sizes[..2], &[5, 5]);
+    }
+
+    #[test]
+    fn test_one_step_maps_match_hom_graph() {
+        let source = c_n_graph(4);
+        let target = c_n_graph(5);
+        let (maps, _) = generate_maps_naive(&source, &target);
+        let hom = hom_graph(&maps);
+        for (i, f) in maps.iter().enumerate() {
+            let images = f.mapped_vertices().collect::<Vec<_>>();
+            let mut steps = one_step_maps(&source, &target, &images);
+            steps.sort();
+            let mut expected = hom
+                .neighbors(i as u32)
+                .map(|j| maps[j as usize].mapped_vertices().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            expected.sort();
+            assert_eq!(steps, expected);
+        }
+    }
+
+    #[test]
+    fn test_find_homotopy() {
+        let c5 = c_n_graph(5);
+        let rotation = |k: u32| {
+            VertGraphMap::try_from(
+                Cow::Borrowed(&c5),
+                Cow::Borrowed(&c5),
+                (0..5).map(|u| (u + k) % 5),
+                &mut [0; 5],
+            )
+            .unwrap()
+        };
+        let reflection = VertGraphMap::try_from(
+            Cow::Borrowed(&c5),
+            Cow::Borrowed(&c5),
+            (0..5).map(|u| (5 - u) % 5),
+            &mut [0; 5],
+        )
+        .unwrap();
+
+        let id = rotation(0);
+        let homotopy = find_homotopy(&id, &rotation(2)).expect("rotations are homotopic");
+        let images = homotopy
+            .iter()
+            .map(|h| h.mapped_vertices().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            images,
+            vec![
+                vec![0, 1, 2, 3, 4],
+                vec![1, 2, 3, 4, 0],
+                vec![2, 3, 4, 0, 1]
+            ]
+        );
+        assert!(find_homotopy(&id, &reflection).is_none());
+        assert_eq!(find_homotopy(&id, &id).unwrap().len(), 1);
+
+        // Everything into C_4 contracts, check the witness step by step
+        let c4 = c_n_graph(4);
+        let id = VertGraphMap::try_from(Cow::Borrowed(&c4), Cow::Borrowed(&c4), 0..4, &mut [0; 4])
+            .unwrap();
+        let constant =
+            VertGraphMap::try_from(Cow::Borrowed(&c4), Cow::Borrowed(&c4), [3; 4], &mut [0; 4])
+                .unwrap();
+        let homotopy = find_homotopy(&id, &constant).expect("C_4 is contractible");
+        assert_eq!(
+            homotopy
+                .first()
+                .unwrap()
+                .mapped_vertices()
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(
+            homotopy
+                .last()
+                .unwrap()
+                .mapped_vertices()
+                .collect::<Vec<_>>(),
+            vec![3; 4]
+        );
+        for pair in homotopy.windows(2) {
+            assert!(is_one_step(&pair[0], &pair[1]));
+            let images = pair[1].mapped_vertices().collect::<Vec<_>>();
+            assert!(VertGraphMap::try_from(
+                Cow::Borrowed(&c4),
+                Cow::Borrowed(&c4),
+                images,
+                &mut [0; 4]
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_hom_graph_type_matches_hom_graph() {
+        let source = c_n_graph(4);
+        let target = c_n_graph(5);
+        let (maps, _) = generate_maps_naive(&source, &target);
+        let expected = hom_graph(&maps);
+
+        // Same vertex order as the naive enumeration, so the adjacency matrices agree
+        let set = maps
+            .iter()
+            .map(|f| f.mapped_vertices().collect())
+            .collect::<MapSet>();
+        let hom = HomGraph::from_maps(source.clone(), target.clone(), set);
+        let adj: AdjMatrix = hom.clone().into();
+        assert_eq!(CSRGraph::from(&hom), expected);
+        assert_eq!(adj, AdjMatrix::from(expected));
+        for i in 0..hom.n() {
+            assert_eq!(hom.index_of(&hom.map(i)), Some(i));
+            assert_eq!(hom.degree(i), hom.neighbors(i).count() as u32);
+            for j in 0..hom.n() {
+                assert_eq!(hom.is_edge(i, j), hom.neighbors(i).any(|k| k == j));
+            }
+        }
+
+        assert_eq!(
+            HomGraph::new(source.clone(), target.clone()).n() as usize,
+            maps.len()
+        );
+    }
+
+    #[test]
+    fn test_hom_graph_homology() {
+        // Hom(C_5, C_5) has a component for each winding number -1, 0 and 1
+        let c5 = c_n_graph(5);
+        let hom = HomGraph::new(c5.clone(), c5);
+        let betti = betti_numbers(&hom, 0, CubeComplex::Normalized);
+        assert_eq!(betti, vec![3]);
+        assert_eq!(connected_components(&hom).len(), 3);
+    }
+
+    #[test]
+    fn test_cube_maps_are_contractible() {
+        let source = CubeGraph::new(2);
+        let target = greene_sphere();
+        let (maps, _) = generate_maps_naive_stack(&source, &target);
+        assert_eq!(homotopy_classes(&maps).len(), 1);
+    }
+}
@@ -1,6 +1,10 @@
 pub mod cube_isomorphism;
 pub mod cube_maps;
+pub mod cycle_maps;
+pub mod equivalence;
+pub mod hom_graph;
 pub mod permutation_generator;
+pub mod pointed;
 pub mod stack_map;
 use crate::prelude::*;
 use std::borrow::Cow;
@@ -16,6 +20,9 @@ where
     fn map(&self, u: u32) -> u32;
     fn mapped_vertices(&self) -> impl Iterator<Item = u32>;
 
+    /// # Safety
+    ///
+    /// `mapped_vertices` must be a valid graph map from `new_domain` into the codomain
     unsafe fn change_domain(
         &self,
         new_domain: U,
@@ -37,6 +44,10 @@ where
 #[derive(Debug)]
 pub enum GraphMapError {
     BadEdge(u32, u32, u32, u32),
+    // (basepoint, image of basepoint, expected image)
+    BadBasepoint(u32, u32, u32),
+    // (domain size, codomain size) of maps too large for the representation
+    TooLarge(u32, u32),
 }
 
 impl<'u, 'v, U, V> VertGraphMap<'u, 'v, U, V>
@@ -109,6 +120,38 @@ where
     }
 }
 
+impl<'u, U> VertGraphMap<'u, 'u, U, U>
+where
+    U: UGraph,
+{
+    /// The identity map on `graph`
+    pub fn identity(graph: &'u U) -> Self {
+        Self {
+            domain: Cow::Borrowed(graph),
+            codomain: Cow::Borrowed(graph),
+            vert_maps: (0..graph.n()).collect(),
+        }
+    }
+}
+
+/// The composite `g ∘ f`, borrowing the domain of `f` and the codomain of `g`.
+///
+/// Panics if the codomain of `f` is not the domain of `g`.
+pub fn compose<'u, 'w, U: UGraph, V: UGraph, W: UGraph>(
+    f: &'u impl GraphMap<U, V>,
+    g: &'w impl GraphMap<V, W>,
+) -> VertGraphMap<'u, 'w, U, W> {
+    assert!(
+        f.codomain() == g.domain(),
+        "cannot compose: codomain of f is not domain of g"
+    );
+    VertGraphMap {
+        domain: Cow::Borrowed(f.domain()),
+        codomain: Cow::Borrowed(g.codomain()),
+        vert_maps: f.mapped_vertices().map(|v| g.map(v)).collect(),
+    }
+}
+
 pub fn generate_maps_naive<'u, 'v, U: UGraph, V: UGraph>(
     source: &'u U,
     target: &'v V,
@@ -245,6 +288,7 @@ mod tests {
             Err(GraphMapError::BadEdge(_v1, _v2, _m1, _m2)) => {
                 // Expected error
             }
+            Err(err) => panic!("Expected BadEdge error, got {err:?}"),
             Ok(_) => {
                 panic!(
                     "Expected BadEdge error, but GraphMap was created successfully.\n\
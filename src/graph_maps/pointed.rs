@@ -0,0 +1,312 @@
+use std::borrow::Cow;
+
+use crate::graph_maps::hom_graph::find_homotopy_fixing;
+use crate::graph_maps::permutation_generator::PermutationGenerator;
+use crate::graph_maps::stack_map::StackGraphMap;
+use crate::graph_maps::{GraphMap, GraphMapError, VertGraphMap};
+use crate::prelude::*;
+
+/// A graph map sending the basepoint `u0` of its domain to the basepoint `v0` of its codomain.
+///
+/// Pointed maps with the same basepoints that are one step apart are also one step apart as
+/// based maps, so [hom_graph](crate::graph_maps::hom_graph::hom_graph) and
+/// [homotopy_classes](crate::graph_maps::hom_graph::homotopy_classes) over the output of
+/// [generate_pointed_maps_naive] give based homotopy classes.
+#[derive(Debug, Clone)]
+pub struct PointedGraphMap<'u, 'v, U, V>
+where
+    U: UGraph,
+    V: UGraph,
+{
+    map: VertGraphMap<'u, 'v, U, V>,
+    basepoint: u32,
+}
+
+impl<'u, 'v, U, V> PointedGraphMap<'u, 'v, U, V>
+where
+    U: UGraph,
+    V: UGraph,
+{
+    /// Points `map` at `basepoint`, whose image becomes the basepoint of the codomain.
+    pub fn new(map: VertGraphMap<'u, 'v, U, V>, basepoint: u32) -> Self {
+        assert!(
+            basepoint < map.domain().n(),
+            "basepoint {basepoint} out of range {}",
+            map.domain().n()
+        );
+        Self { map, basepoint }
+    }
+
+    /// Validates `mapped_verts` as a graph map sending `basepoint.0` to `basepoint.1`.
+    pub fn try_from(
+        domain: impl Into<Cow<'u, U>>,
+        codomain: impl Into<Cow<'v, V>>,
+        basepoint: (u32, u32),
+        mapped_verts: impl IntoIterator<Item = u32>,
+        // Workspace is a length n mutable array
+        workspace: &mut [u32],
+    ) -> Result<Self, GraphMapError> {
+        let (u0, v0) = basepoint;
+        let map = Self::new(
+            VertGraphMap::try_from(domain, codomain, mapped_verts, workspace)?,
+            u0,
+        );
+        let image = map.target_basepoint();
+        if image != v0 {
+            return Err(GraphMapError::BadBasepoint(u0, image, v0));
+        }
+        Ok(map)
+    }
+
+    pub fn basepoint(&self) -> u32 {
+        self.basepoint
+    }
+
+    pub fn target_basepoint(&self) -> u32 {
+        self.map.map(self.basepoint)
+    }
+
+    // Consumes self and returns the unpointed map
+    pub fn into_inner(self) -> VertGraphMap<'u, 'v, U, V> {
+        self.map
+    }
+}
+
+impl<U, V> GraphMap<U, V> for PointedGraphMap<'_, '_, U, V>
+where
+    U: UGraph,
+    V: UGraph,
+{
+    fn domain(&self) -> &U {
+        self.map.domain()
+    }
+    fn codomain(&self) -> &V {
+        self.map.codomain()
+    }
+    fn map(&self, u: u32) -> u32 {
+        self.map.map(u)
+    }
+    fn mapped_vertices(&self) -> impl Iterator<Item = u32> {
+        self.map.mapped_vertices()
+    }
+
+    /// # Safety
+    ///
+    /// As for [VertGraphMap], and the basepoint must still be a vertex of `new_domain`
+    unsafe fn change_domain(
+        &self,
+        new_domain: U,
+        mapped_vertices: impl IntoIterator<Item = u32>,
+    ) -> Self {
+        Self {
+            map: self.map.change_domain(new_domain, mapped_vertices),
+            basepoint: self.basepoint,
+        }
+    }
+}
+
+/// All graph maps `f: source -> target` with `f(u0) = v0`, and the number of candidate maps
+/// checked.
+///
+/// Only the `|V|^(|U| - 1)` assignments with the basepoint already fixed are checked, rather
+/// than filtering the `|V|^|U|` maps of [generate_maps_naive](crate::graph_maps::generate_maps_naive).
+pub fn generate_pointed_maps_naive<'u, 'v, U: UGraph, V: UGraph>(
+    source: &'u U,
+    u0: u32,
+    target: &'v V,
+    v0: u32,
+) -> (Vec<PointedGraphMap<'u, 'v, U, V>>, u64) {
+    let n = source.n() as usize;
+    let m = target.n() as usize;
+    assert!(u0 < source.n() && v0 < target.n());
+    let total_checks = (m as u64).pow(n as u32 - 1);
+    let mut generator = PermutationGenerator::new(n as u32 - 1, m as u32, 0);
+
+    let mut maps: Vec<PointedGraphMap<'u, 'v, U, V>> = Vec::new();
+    let mut workspace: Vec<u32> = vec![0; n];
+
+    for _ in 0..total_checks {
+        let mut rest = generator.next().unwrap();
+        let next_iter = (0..n as u32).map(|u| if u == u0 { v0 } else { rest.next().unwrap() });
+        let valid_map = VertGraphMap::try_from(
+            Cow::Borrowed(source),
+            Cow::Borrowed(target),
+            next_iter,
+            &mut workspace,
+        );
+        if let Ok(map) = valid_map {
+            maps.push(PointedGraphMap::new(map, u0));
+        }
+    }
+    let last = generator.next();
+    debug_assert!(
+        last.is_none(),
+        "Permutation generator not exhausted {last:?} {generator:?}"
+    );
+    (maps, total_checks)
+}
+
+/// Like [generate_pointed_maps_naive], but packing the maps as
+/// [generate_maps_naive_stack](crate::graph_maps::stack_map::generate_maps_naive_stack) does.
+/// The basepoint is not recorded, every map sends `u0` to `v0`.
+pub fn generate_pointed_maps_naive_stack<'u, 'v, U: UGraph, V: UGraph>(
+    source: &'u U,
+    u0: u32,
+    target: &'v V,
+    v0: u32,
+) -> (Vec<StackGraphMap<'u, 'v, U, V, u64>>, u64) {
+    let n = source.n() as usize;
+    let m = target.n() as usize;
+    assert!(u0 < source.n() && v0 < target.n());
+    let total_checks = (m as u64).pow(n as u32 - 1);
+    let mut generator = PermutationGenerator::new(n as u32 - 1, m as u32, 0);
+
+    let mut maps: Vec<StackGraphMap<'u, 'v, U, V, u64>> = Vec::new();
+    let mut workspace: Vec<u32> = vec![0; n];
+
+    for _ in 0..total_checks {
+        let mut rest = generator.next().unwrap();
+        let next_iter = (0..n as u32).map(|u| if u == u0 { v0 } else { rest.next().unwrap() });
+        let valid_map = StackGraphMap::try_from(
+            Cow::Borrowed(source),
+            Cow::Borrowed(target),
+            next_iter,
+            &mut workspace,
+        );
+        if let Ok(map) = valid_map {
+            maps.push(map);
+        }
+    }
+    let last = generator.next();
+    debug_assert!(
+        last.is_none(),
+        "Permutation generator not exhausted {last:?} {generator:?}"
+    );
+    (maps, total_checks)
+}
+
+/// Decides whether `f` and `g` are homotopic through maps fixing the basepoint, returning
+/// the homotopy as in [find_homotopy](crate::graph_maps::hom_graph::find_homotopy).
+pub fn find_pointed_homotopy<'a, U: UGraph, V: UGraph>(
+    f: &'a PointedGraphMap<'_, '_, U, V>,
+    g: &PointedGraphMap<'_, '_, U, V>,
+) -> Option<Vec<VertGraphMap<'a, 'a, U, V>>> {
+    assert!(
+        f.basepoint() == g.basepoint() && f.target_basepoint() == g.target_basepoint(),
+        "maps must have the same basepoints"
+    );
+    find_homotopy_fixing(f, g, &[f.basepoint()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::generate_maps_naive;
+    use crate::graph_maps::hom_graph::{find_homotopy, homotopy_classes};
+    use crate::graph_maps::stack_map::generate_maps_naive_stack;
+    use crate::graphs::extras::{c_n_graph, greene_sphere};
+
+    #[test]
+    fn test_pointed_map_basepoint() {
+        let c5 = c_n_graph(5);
+        let mut workspace = [0; 5];
+        let map = PointedGraphMap::try_from(
+            Cow::Borrowed(&c5),
+            Cow::Borrowed(&c5),
+            (0, 1),
+            [1, 2, 3, 4, 0],
+            &mut workspace,
+        )
+        .unwrap();
+        assert_eq!(map.basepoint(), 0);
+        assert_eq!(map.target_basepoint(), 1);
+
+        let bad = PointedGraphMap::try_from(
+            Cow::Borrowed(&c5),
+            Cow::Borrowed(&c5),
+            (0, 0),
+            [1, 2, 3, 4, 0],
+            &mut workspace,
+        );
+        match bad {
+            Err(GraphMapError::BadBasepoint(0, 1, 0)) => {}
+            other => panic!("Expected BadBasepoint error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_generate_pointed_maps_matches_filter() {
+        let source = c_n_graph(4);
+        let target = greene_sphere();
+        for (u0, v0) in [(0, 0), (2, 9), (3, 4)] {
+            let (pointed, checks) = generate_pointed_maps_naive(&source, u0, &target, v0);
+            assert_eq!(checks, 10u64.pow(3));
+
+            let (all, _) = generate_maps_naive(&source, &target);
+            let filtered = all
+                .iter()
+                .filter(|f| f.map(u0) == v0)
+                .map(|f| f.mapped_vertices().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let pointed = pointed
+                .iter()
+                .map(|f| f.mapped_vertices().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            assert_eq!(pointed, filtered);
+        }
+    }
+
+    #[test]
+    fn test_generate_pointed_stack_maps_matches_filter() {
+        let source = c_n_graph(4);
+        let target = greene_sphere();
+        for (u0, v0) in [(0, 0), (2, 9), (3, 4)] {
+            let (pointed, checks) = generate_pointed_maps_naive_stack(&source, u0, &target, v0);
+            assert_eq!(checks, 10u64.pow(3));
+
+            let (all, _) = generate_maps_naive_stack(&source, &target);
+            let filtered = all
+                .iter()
+                .filter(|f| f.map(u0) == v0)
+                .map(|f| f.mapped_vertices().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let pointed = pointed
+                .iter()
+                .map(|f| f.mapped_vertices().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            assert_eq!(pointed, filtered);
+        }
+    }
+
+    #[test]
+    fn test_pointed_homotopy_fixes_basepoint() {
+        let c5 = c_n_graph(5);
+        let (maps, _) = generate_pointed_maps_naive(&c5, 0, &c5, 0);
+        let id = maps.iter().find(|f| f.mapped_vertices().eq(0..5)).unwrap();
+        let homotopy = find_pointed_homotopy(id, id).unwrap();
+        assert_eq!(homotopy.len(), 1);
+
+        // Based maps C_5 -> C_5 fall into the classes of winding number -1, 0 and 1, each
+        // of the non-zero ones containing only the identity or the reflection.
+        let mut sizes = homotopy_classes(&maps)
+            .iter()
+            .map(|class| class.len())
+            .collect::<Vec<_>>();
+        sizes.sort_unstable();
+        assert_eq!(sizes.len(), 3);
+        assert_eq!(&sizes[..2], &[1, 1]);
+
+        // Every based loop into C_4 contracts, with the basepoint fixed throughout
+        let c4 = c_n_graph(4);
+        let (maps, _) = generate_pointed_maps_naive(&c5, 0, &c4, 0);
+        let constant = maps
+            .iter()
+            .find(|f| f.mapped_vertices().all(|v| v == 0))
+            .unwrap();
+        for f in &maps {
+            let homotopy = find_pointed_homotopy(f, constant).expect("C_4 is contractible");
+            assert!(homotopy.iter().all(|h| h.map(0) == 0));
+            assert!(find_homotopy(f, constant).unwrap().len() <= homotopy.len());
+        }
+    }
+}
@@ -1,5 +1,7 @@
+use crate::graph_maps::cube_maps::{CubeDegeneracy, CubeFaces};
 use crate::graph_maps::permutation_generator::{self, PermutationIterator};
 use crate::graph_maps::{GraphMap, GraphMapError, VertGraphMap};
+use crate::graphs::cube::CubeGraph;
 use crate::graphs::UGraph;
 use crate::prelude::*;
 use num_traits::{PrimInt, Unsigned};
@@ -151,7 +153,7 @@ impl<'u, 'v, U: UGraph, V: UGraph, T: PrimInt + Unsigned> From<VertGraphMap<'u,
     }
 }
 
-impl<'u, 'v, 'a, U: UGraph, V: UGraph, T: PrimInt + Unsigned> From<&'a VertGraphMap<'u, 'v, U, V>>
+impl<'u, 'v, U: UGraph, V: UGraph, T: PrimInt + Unsigned> From<&VertGraphMap<'u, 'v, U, V>>
     for StackGraphMap<'u, 'v, U, V, T>
 {
     fn from(value: &VertGraphMap<'u, 'v, U, V>) -> Self {
@@ -190,11 +192,7 @@ where
         new_domain: U,
         mapped_vertices: impl IntoIterator<Item = u32>,
     ) -> Self {
-        let vert_map = Self::slice_convert(
-            mapped_vertices.into_iter(),
-            new_domain.n(),
-            self.codomain.n(),
-        );
+        let vert_map = Self::slice_convert(mapped_vertices, new_domain.n(), self.codomain.n());
         Self {
             domain: Cow::Owned(new_domain),
             codomain: self.codomain.clone(),
@@ -203,6 +201,86 @@ where
     }
 }
 
+// Coordinate i is degenerate when every pair of neighbouring 2^i-digit blocks agrees, so
+// the blocks are compared straight from the packed integer.
+impl<D: Dim, V: UGraph, T: PrimInt + Unsigned> CubeDegeneracy<D, V>
+    for StackGraphMap<'_, '_, CubeGraph<D>, V, T>
+{
+    fn degenerate_mask(&self) -> u32 {
+        let dim = self.domain.dim().size();
+        let codomain_n = T::from(self.codomain.n()).unwrap();
+        let mut mask = 0u32;
+        for i in 0..dim {
+            let block = codomain_n.pow(1 << i);
+            let mut rest = self.vert_maps;
+            let degenerate = (0..1u32 << (dim - i - 1)).all(|_| {
+                let low = rest % block;
+                rest = rest / block;
+                let high = rest % block;
+                rest = rest / block;
+                low == high
+            });
+            if degenerate {
+                mask |= 1 << i;
+            }
+        }
+        mask
+    }
+}
+
+// The image of vertex v is digit v in base codomain_n, so the images of the 2^i-vertex
+// blocks of a cube alternate between the two faces along coordinate i.
+impl<'u, 'v, D: StepDim, V: UGraph, T: PrimInt + Unsigned> CubeFaces<D, V>
+    for StackGraphMap<'u, 'v, CubeGraph<D>, V, T>
+{
+    type Face = StackGraphMap<'u, 'v, CubeGraph<D::Prev>, V, T>;
+    type Glued = StackGraphMap<'u, 'v, CubeGraph<D::Next>, V, T>;
+
+    fn face(&self, i: u32, sign: bool) -> Self::Face {
+        let dim = self.domain.dim().size();
+        assert!(dim != 0u32);
+        debug_assert!(i < dim);
+        let codomain_n = T::from(self.codomain.n()).unwrap();
+        let block = codomain_n.pow(1 << i);
+        let num_blocks = 1u32 << (dim - i);
+
+        let mut rest = self.vert_maps;
+        let mut vert_maps = T::zero();
+        let mut scale = T::one();
+        for j in 0..num_blocks {
+            let digits = rest % block;
+            rest = rest / block;
+            if (j & 1 == 1) == sign {
+                if j > 1 {
+                    scale = scale * block;
+                }
+                vert_maps = vert_maps + digits * scale;
+            }
+        }
+        StackGraphMap {
+            domain: Cow::Owned(self.domain.prev()),
+            codomain: self.codomain.clone(),
+            vert_maps,
+        }
+    }
+
+    unsafe fn glue(&self, other: &Self) -> Self::Glued {
+        debug_assert!(self.domain == other.domain);
+        debug_assert!(self.can_glue());
+        let codomain_n = T::from(self.codomain.n()).unwrap();
+        let shift = codomain_n.pow(self.domain.n());
+        StackGraphMap {
+            domain: Cow::Owned(self.domain.next()),
+            codomain: self.codomain.clone(),
+            vert_maps: self.vert_maps + other.vert_maps * shift,
+        }
+    }
+
+    fn can_glue(&self) -> bool {
+        check_fits::<T>(2 * self.domain.n(), self.codomain.n())
+    }
+}
+
 type T = u64;
 pub fn generate_maps_naive_stack<'u, 'v, U: UGraph, V: UGraph>(
     source: &'u U,
@@ -243,7 +321,7 @@ pub fn generate_maps_naive_stack<'u, 'v, U: UGraph, V: UGraph>(
 mod tests {
     use super::*;
     use crate::graph_maps::cube_maps::get_valid_graph_map;
-    use crate::graphs::cube::{CubeGraph, Newable};
+    use crate::graphs::cube::Newable;
     use crate::graphs::extras;
 
     #[test]
@@ -302,6 +380,60 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_face_and_glue_parity_with_vert_map() {
+        use arbtest::arbtest;
+        arbtest(|u| {
+            let dim = 3;
+            let source = CubeGraph::new(dim);
+            let target = extras::c_n_graph(5);
+
+            let map = get_valid_graph_map(&source, &target, u.arbitrary()?);
+            let stack_map: StackGraphMap<'_, '_, _, _, u64> = (&map).into();
+
+            for i in 0..dim {
+                for sign in [false, true] {
+                    let vert_face = map.face(i, sign);
+                    let stack_face = stack_map.face(i, sign);
+                    assert_eq!(stack_face.domain().dim(), dim - 1);
+                    assert_eq!(
+                        stack_face.mapped_vertices().collect::<Vec<_>>(),
+                        vert_face.vert_maps,
+                        "face({i}, {sign}) mismatch"
+                    );
+                }
+            }
+
+            let (lower, upper) = (stack_map.face(0, false), stack_map.face(0, true));
+            let glued = unsafe { lower.glue(&upper) };
+            let vert_glued = unsafe { map.face(0, false).glue(&map.face(0, true)) };
+            assert_eq!(glued.domain().dim(), dim);
+            assert_eq!(
+                glued.mapped_vertices().collect::<Vec<_>>(),
+                vert_glued.vert_maps
+            );
+            assert_eq!(glued.face(dim - 1, false).vert_maps, lower.vert_maps);
+            assert_eq!(glued.face(dim - 1, true).vert_maps, upper.vert_maps);
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_combine_too_large() {
+        use crate::graph_maps::cube_maps::CubeMap;
+        // Maps of the edge into C_5 fit in a u8, maps of the square do not
+        let source = CubeGraph::new(1);
+        let target = extras::c_n_graph(5);
+        let map = get_valid_graph_map(&source, &target, 0);
+        let stack_map: StackGraphMap<'_, '_, _, _, u8> = (&map).into();
+        assert!(!stack_map.can_glue());
+        let cube_map = CubeMap::from(stack_map);
+        assert!(matches!(
+            cube_map.try_combine(&cube_map, true),
+            Err(GraphMapError::TooLarge(4, 5))
+        ));
+    }
+
     #[test]
     fn test_check_fits() {
         // Test check_fits with various parameters
@@ -0,0 +1,285 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::graph_maps::VertGraphMap;
+use crate::prelude::*;
+
+/// The finite groups Cayley graphs can be built from, with elements as vectors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Group {
+    /// Permutations of `0..degree`, multiplied as `(a b)(i) = a(b(i))`
+    Permutation { degree: usize },
+    /// `Z_(n_1) × ... × Z_(n_k)`, added coordinatewise
+    Abelian { moduli: Vec<u32> },
+}
+
+impl Group {
+    fn identity(&self) -> Vec<u32> {
+        match self {
+            Group::Permutation { degree } => (0..*degree as u32).collect(),
+            Group::Abelian { moduli } => vec![0; moduli.len()],
+        }
+    }
+
+    fn multiply(&self, a: &[u32], b: &[u32]) -> Vec<u32> {
+        match self {
+            Group::Permutation { .. } => b.iter().map(|&i| a[i as usize]).collect(),
+            Group::Abelian { moduli } => a
+                .iter()
+                .zip(b)
+                .zip(moduli)
+                .map(|((&x, &y), &n)| (x + y) % n)
+                .collect(),
+        }
+    }
+
+    fn inverse(&self, a: &[u32]) -> Vec<u32> {
+        match self {
+            Group::Permutation { .. } => {
+                let mut inverse = vec![0; a.len()];
+                for (i, &j) in a.iter().enumerate() {
+                    inverse[j as usize] = i as u32;
+                }
+                inverse
+            }
+            Group::Abelian { moduli } => a.iter().zip(moduli).map(|(&x, &n)| (n - x) % n).collect(),
+        }
+    }
+}
+
+/// The reflexive Cayley graph of a finite group with respect to a set of generators: elements
+/// `x` and `x s` are adjacent for every generator `s`.
+///
+/// The group acts on the graph by left multiplication, which gives the automorphisms
+/// [CayleyGraph::left_action].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CayleyGraph {
+    group: Group,
+    graph: CSRGraph,
+    elements: Vec<Vec<u32>>,
+    index: HashMap<Vec<u32>, u32>,
+    generators: Vec<Vec<u32>>,
+}
+
+impl CayleyGraph {
+    /// The Cayley graph of the permutation group generated by `generators`, permutations of
+    /// `0..degree` given as image vectors. Elements are numbered in breadth-first order from
+    /// the identity, which is vertex 0.
+    pub fn permutation_group(degree: u32, generators: &[Vec<u32>]) -> Self {
+        let group = Group::Permutation {
+            degree: degree as usize,
+        };
+        for generator in generators {
+            let mut seen = vec![false; degree as usize];
+            assert!(
+                generator.len() == degree as usize
+                    && generator
+                        .iter()
+                        .all(|&j| j < degree && !std::mem::replace(&mut seen[j as usize], true)),
+                "{generator:?} is not a permutation of 0..{degree}"
+            );
+        }
+        let mut elements = vec![group.identity()];
+        let mut index = HashMap::from([(group.identity(), 0)]);
+        let mut head = 0;
+        while head < elements.len() {
+            for generator in generators {
+                let next = group.multiply(&elements[head], generator);
+                if !index.contains_key(&next) {
+                    index.insert(next.clone(), elements.len() as u32);
+                    elements.push(next);
+                }
+            }
+            head += 1;
+        }
+        Self::new(group, elements, index, generators)
+    }
+
+    /// The Cayley graph of `Z_(n_1) × ... × Z_(n_k)` for `moduli = [n_1, ..., n_k]`. Element
+    /// `x` is vertex `x_1 + n_1 x_2 + n_1 n_2 x_3 + ...`, so every element is a vertex even when
+    /// the generators only generate a subgroup.
+    pub fn abelian_group(moduli: &[u32], generators: &[Vec<u32>]) -> Self {
+        assert!(moduli.iter().all(|&n| n >= 1), "moduli must be positive");
+        for generator in generators {
+            assert!(
+                generator.len() == moduli.len()
+                    && generator.iter().zip(moduli).all(|(&x, &n)| x < n),
+                "{generator:?} is not an element of Z_{moduli:?}"
+            );
+        }
+        let group = Group::Abelian {
+            moduli: moduli.to_vec(),
+        };
+        let order = moduli.iter().product::<u32>();
+        let elements = (0..order)
+            .map(|mut v| {
+                moduli
+                    .iter()
+                    .map(|&n| {
+                        let x = v % n;
+                        v /= n;
+                        x
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let index = elements
+            .iter()
+            .enumerate()
+            .map(|(v, x)| (x.clone(), v as u32))
+            .collect();
+        Self::new(group, elements, index, generators)
+    }
+
+    fn new(
+        group: Group,
+        elements: Vec<Vec<u32>>,
+        index: HashMap<Vec<u32>, u32>,
+        generators: &[Vec<u32>],
+    ) -> Self {
+        let inverses = generators
+            .iter()
+            .map(|s| group.inverse(s))
+            .collect::<Vec<_>>();
+        let adjacent = elements
+            .iter()
+            .enumerate()
+            .map(|(v, x)| {
+                let mut neighbors = vec![v as u32];
+                for s in generators.iter().chain(&inverses) {
+                    neighbors.push(index[&group.multiply(x, s)]);
+                }
+                neighbors
+            })
+            .collect();
+        Self {
+            group,
+            graph: CSRGraph::from_neighbor_lists(adjacent),
+            elements,
+            index,
+            generators: generators.to_vec(),
+        }
+    }
+
+    pub fn graph(&self) -> &CSRGraph {
+        &self.graph
+    }
+
+    /// The group element vertex `v` stands for
+    pub fn element(&self, v: u32) -> &[u32] {
+        &self.elements[v as usize]
+    }
+
+    /// The vertex standing for `element`, if it is in the group
+    pub fn index_of(&self, element: &[u32]) -> Option<u32> {
+        self.index.get(element).copied()
+    }
+
+    /// The automorphism `x -> g x` for the element `g` at vertex `v`.
+    pub fn left_action(&self, v: u32) -> VertGraphMap<'_, '_, CSRGraph, CSRGraph> {
+        let g = self.element(v);
+        let images = self
+            .elements
+            .iter()
+            .map(|x| self.index[&self.group.multiply(g, x)])
+            .collect();
+        // Safety: g x and g x s are adjacent whenever x and x s are
+        unsafe {
+            VertGraphMap::new_unchecked(
+                Cow::Borrowed(&self.graph),
+                Cow::Borrowed(&self.graph),
+                Cow::Owned(images),
+            )
+        }
+    }
+
+    /// The automorphisms [CayleyGraph::left_action] of the generators, which generate the
+    /// action of the group (or of the subgroup generated, for [CayleyGraph::abelian_group]).
+    pub fn generator_actions(&self) -> Vec<VertGraphMap<'_, '_, CSRGraph, CSRGraph>> {
+        self.generators
+            .iter()
+            .map(|s| self.left_action(self.index[s]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::{compose, GraphMap};
+    use crate::graphs::connected_components;
+    use crate::graphs::extras::c_n_graph;
+    use crate::graphs::families::TorusGraph;
+
+    fn assert_automorphism(cayley: &CayleyGraph, map: &VertGraphMap<'_, '_, CSRGraph, CSRGraph>) {
+        let graph = cayley.graph();
+        let mut workspace = vec![0; graph.n() as usize];
+        assert!(VertGraphMap::try_from(
+            Cow::Borrowed(graph),
+            Cow::Borrowed(graph),
+            map.mapped_vertices(),
+            &mut workspace
+        )
+        .is_ok());
+        let mut images = map.mapped_vertices().collect::<Vec<_>>();
+        images.sort_unstable();
+        assert!(images.into_iter().eq(0..graph.n()));
+    }
+
+    #[test]
+    fn test_abelian_cayley_graphs() {
+        let cyclic = CayleyGraph::abelian_group(&[5], &[vec![1]]);
+        assert_eq!(cyclic.graph(), &c_n_graph(5));
+
+        let torus = CayleyGraph::abelian_group(&[5, 4], &[vec![1, 0], vec![0, 1]]);
+        let expected: AdjMatrix = TorusGraph::new(vec![5, 4]).into();
+        assert_eq!(torus.graph(), &CSRGraph::try_from(expected).unwrap());
+        assert_eq!(torus.element(7), &[2, 1]);
+        assert_eq!(torus.index_of(&[2, 1]), Some(7));
+
+        // A generator of a proper subgroup leaves a component per coset
+        let cosets = CayleyGraph::abelian_group(&[6], &[vec![2]]);
+        assert_eq!(connected_components(cosets.graph()).len(), 2);
+    }
+
+    #[test]
+    fn test_symmetric_group() {
+        // S_3 generated by two transpositions is a hexagon
+        let s3 = CayleyGraph::permutation_group(3, &[vec![1, 0, 2], vec![0, 2, 1]]);
+        assert_eq!(s3.graph().n(), 6);
+        assert_eq!(s3.element(0), &[0, 1, 2]);
+        assert!((0..6).all(|v| s3.graph().degree(v) == 3));
+        assert_eq!(connected_components(s3.graph()).len(), 1);
+
+        // S_4 from a transposition and a 4-cycle
+        let s4 = CayleyGraph::permutation_group(4, &[vec![1, 0, 2, 3], vec![1, 2, 3, 0]]);
+        assert_eq!(s4.graph().n(), 24);
+        assert_eq!(&assert_reflexive_symmetric(s4.graph()), s4.graph());
+    }
+
+    #[test]
+    fn test_group_action() {
+        let s4 = CayleyGraph::permutation_group(4, &[vec![1, 0, 2, 3], vec![1, 2, 3, 0]]);
+        let actions = s4.generator_actions();
+        for action in &actions {
+            assert_automorphism(&s4, action);
+        }
+        // The action is a homomorphism: acting by g then h is acting by h g
+        let (g, h) = (
+            s4.index_of(&[1, 0, 2, 3]).unwrap(),
+            s4.index_of(&[1, 2, 3, 0]).unwrap(),
+        );
+        let hg = s4
+            .index_of(&s4.group.multiply(s4.element(h), s4.element(g)))
+            .unwrap();
+        assert!(compose(&s4.left_action(g), &s4.left_action(h))
+            .mapped_vertices()
+            .eq(s4.left_action(hg).mapped_vertices()));
+        assert!(s4.left_action(0).mapped_vertices().eq(0..24));
+
+        let torus = CayleyGraph::abelian_group(&[3, 5], &[vec![1, 0], vec![0, 1]]);
+        for v in 0..torus.graph().n() {
+            assert_automorphism(&torus, &torus.left_action(v));
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+
+use crate::graph_maps::VertGraphMap;
+use crate::prelude::*;
+
+/// The k-fold cover of `graph` given by permutation voltages on its edges.
+///
+/// `voltages[(u, v)]` for `u < v` is a permutation `σ` of `0..k`, and the cover has an edge
+/// `(u, i) - (v, σ(i))`; edges without a voltage get the identity. The lift `(v, i)` is vertex
+/// `v * k + i`, and is returned with the covering projection `(v, i) -> v`.
+///
+/// This is a covering of graphs. It is a covering in the discrete homotopy sense when the
+/// voltages multiply to the identity around every 3- and 4-cycle, otherwise those cycles do
+/// not lift to cycles.
+pub fn voltage_cover<'g, G: UGraph>(
+    graph: &'g G,
+    k: u32,
+    voltages: &HashMap<(u32, u32), Vec<u32>>,
+) -> (CSRGraph, VertGraphMap<'static, 'g, CSRGraph, G>) {
+    let mut inverses = HashMap::new();
+    for (&(u, v), perm) in voltages {
+        assert!(
+            u < v && graph.is_edge(u, v),
+            "voltage on non-edge ({u}, {v})"
+        );
+        assert!(
+            perm.len() == k as usize,
+            "voltage on ({u}, {v}) is not on {k} sheets"
+        );
+        let mut inverse = vec![k; k as usize];
+        for (i, &j) in perm.iter().enumerate() {
+            assert!(
+                j < k && inverse[j as usize] == k,
+                "voltage on ({u}, {v}) is not a permutation"
+            );
+            inverse[j as usize] = i as u32;
+        }
+        inverses.insert((u, v), inverse);
+    }
+
+    let mut offsets = vec![0u32];
+    let mut neighbor_list = Vec::new();
+    for v in 0..graph.n() {
+        for i in 0..k {
+            let mut neighbors = graph
+                .neighbors(v)
+                .map(|w| {
+                    let sheet = match (v.cmp(&w), voltages.get(&(v.min(w), v.max(w)))) {
+                        (std::cmp::Ordering::Less, Some(perm)) => perm[i as usize],
+                        (std::cmp::Ordering::Greater, Some(_)) => inverses[&(w, v)][i as usize],
+                        _ => i,
+                    };
+                    w * k + sheet
+                })
+                .collect::<Vec<_>>();
+            neighbors.sort_unstable();
+            neighbor_list.extend(neighbors);
+            offsets.push(neighbor_list.len() as u32);
+        }
+    }
+    let cover = CSRGraph::new(offsets, neighbor_list);
+    let projection = (0..cover.n()).map(|x| x / k).collect();
+    // Safety: lifted edges lie over edges of the graph
+    let projection = unsafe {
+        VertGraphMap::new_unchecked(
+            Cow::Owned(cover.clone()),
+            Cow::Borrowed(graph),
+            Cow::Owned(projection),
+        )
+    };
+    (cover, projection)
+}
+
+/// The ball of radius `radius` around the lift of `basepoint` in the universal cover of
+/// `graph` with respect to the 2-complex with a cell on every 3- and 4-cycle.
+///
+/// Lifts of walks from the basepoint are built up and identified coset-enumeration style:
+/// lifts of walks that differ by backtracking or by going around a 3- or 4-cycle are merged.
+/// Cells are only filled at lifts inside the ball, so two lifts whose walks are homotopic only
+/// through walks leaving the ball are not identified; a larger radius refines the result.
+///
+/// The lift of the basepoint is vertex 0 and the rest are in breadth-first order. Returned
+/// with the covering projection.
+pub fn universal_cover<G: UGraph>(
+    graph: &G,
+    basepoint: u32,
+    radius: u32,
+) -> (CSRGraph, VertGraphMap<'static, '_, CSRGraph, G>) {
+    let mut lifts = Lifts::new(graph, basepoint);
+    let ball = loop {
+        let ball = lifts.ball(radius);
+        let pending = ball
+            .iter()
+            .copied()
+            .filter(|&x| !lifts.processed[x])
+            .collect::<Vec<_>>();
+        if pending.is_empty() {
+            break ball;
+        }
+        for x in pending {
+            lifts.fill_cells(x);
+        }
+    };
+
+    let index = ball
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| (x, i as u32))
+        .collect::<HashMap<_, _>>();
+    let mut offsets = vec![0u32];
+    let mut neighbor_list = Vec::new();
+    for &x in &ball {
+        let adjacent = lifts.table[x].values().copied().collect::<Vec<_>>();
+        let mut neighbors = adjacent
+            .into_iter()
+            .filter_map(|y| index.get(&lifts.find(y)).copied())
+            .collect::<Vec<_>>();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbor_list.extend(neighbors);
+        offsets.push(neighbor_list.len() as u32);
+    }
+    let cover = CSRGraph::new(offsets, neighbor_list);
+    let projection = ball.iter().map(|&x| lifts.proj[x]).collect();
+    // Safety: lifts are only adjacent over adjacent vertices
+    let projection = unsafe {
+        VertGraphMap::new_unchecked(
+            Cow::Owned(cover.clone()),
+            Cow::Borrowed(graph),
+            Cow::Owned(projection),
+        )
+    };
+    (cover, projection)
+}
+
+/// Lifts of walks, merged with union-find as they are found to coincide.
+struct Lifts<'g, G: UGraph> {
+    graph: &'g G,
+    /// The vertex each lift lies over
+    proj: Vec<u32>,
+    parent: Vec<usize>,
+    /// The adjacent lift over each neighbour, only kept up to date for representatives
+    table: Vec<HashMap<u32, usize>>,
+    /// Whether the cells at a lift have been filled
+    processed: Vec<bool>,
+}
+
+impl<'g, G: UGraph> Lifts<'g, G> {
+    fn new(graph: &'g G, basepoint: u32) -> Self {
+        let mut lifts = Self {
+            graph,
+            proj: Vec::new(),
+            parent: Vec::new(),
+            table: Vec::new(),
+            processed: Vec::new(),
+        };
+        lifts.new_lift(basepoint);
+        lifts
+    }
+
+    fn new_lift(&mut self, v: u32) -> usize {
+        let x = self.proj.len();
+        self.proj.push(v);
+        self.parent.push(x);
+        self.table.push(HashMap::from([(v, x)]));
+        self.processed.push(false);
+        x
+    }
+
+    fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// The lift over `w` adjacent to `x`, created if there is none yet.
+    fn lift(&mut self, x: usize, w: u32) -> usize {
+        let x = self.find(x);
+        if let Some(&y) = self.table[x].get(&w) {
+            return self.find(y);
+        }
+        let y = self.new_lift(w);
+        self.table[x].insert(w, y);
+        self.table[y].insert(self.proj[x], x);
+        y
+    }
+
+    /// Makes `y` and `z` adjacent, merging them with any other lifts adjacent in their place.
+    fn join(&mut self, y: usize, z: usize) {
+        for (a, b) in [(y, z), (z, y)] {
+            let (a, b) = (self.find(a), self.find(b));
+            match self.table[a].get(&self.proj[b]) {
+                Some(&c) => self.coincide(c, b),
+                None => {
+                    self.table[a].insert(self.proj[b], b);
+                }
+            }
+        }
+    }
+
+    /// Merges `a` and `b` along with every pair of lifts this forces to coincide.
+    fn coincide(&mut self, a: usize, b: usize) {
+        let mut queue = vec![(a, b)];
+        while let Some((a, b)) = queue.pop() {
+            let (a, b) = (self.find(a), self.find(b));
+            if a == b {
+                continue;
+            }
+            debug_assert_eq!(self.proj[a], self.proj[b]);
+            // Keep the older lift, so that the basepoint lift stays its own representative
+            let (keep, gone) = (a.min(b), a.max(b));
+            self.parent[gone] = keep;
+            self.processed[keep] |= self.processed[gone];
+            for (w, y) in std::mem::take(&mut self.table[gone]) {
+                match self.table[keep].get(&w) {
+                    Some(&z) => queue.push((z, y)),
+                    None => {
+                        self.table[keep].insert(w, y);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Lifts every neighbour of `x` and fills the 3- and 4-cycles through it.
+    fn fill_cells(&mut self, x: usize) {
+        let x = self.find(x);
+        if self.processed[x] {
+            return;
+        }
+        self.processed[x] = true;
+        let graph = self.graph;
+        let s = self.proj[x];
+        let neighbors = graph.neighbors(s).filter(|&a| a != s).collect::<Vec<_>>();
+        for &a in &neighbors {
+            self.lift(x, a);
+        }
+        for (i, &a) in neighbors.iter().enumerate() {
+            for &b in &neighbors[i + 1..] {
+                if graph.is_edge(a, b) {
+                    let (y, z) = (self.lift(x, a), self.lift(x, b));
+                    self.join(y, z);
+                    continue;
+                }
+                // 4-cycles s - a - c - b with a chord from s are filled by two triangles
+                let corners = graph
+                    .neighbors(a)
+                    .filter(|&c| !graph.is_edge(s, c) && graph.is_edge(b, c))
+                    .collect::<Vec<_>>();
+                for c in corners {
+                    let y = self.lift(x, a);
+                    let z = self.lift(y, c);
+                    let y = self.lift(x, b);
+                    self.join(y, z);
+                }
+            }
+        }
+    }
+
+    /// Representatives within `radius` steps of the basepoint lift, in breadth-first order.
+    fn ball(&mut self, radius: u32) -> Vec<usize> {
+        let root = self.find(0);
+        let mut dist = HashMap::from([(root, 0u32)]);
+        let mut order = vec![root];
+        let mut queue = VecDeque::from([root]);
+        while let Some(x) = queue.pop_front() {
+            if dist[&x] == radius {
+                continue;
+            }
+            let next = self.table[x].values().copied().collect::<Vec<_>>();
+            for y in next {
+                let y = self.find(y);
+                if !dist.contains_key(&y) {
+                    dist.insert(y, dist[&x] + 1);
+                    order.push(y);
+                    queue.push_back(y);
+                }
+            }
+        }
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::GraphMap;
+    use crate::graphs::connected_components;
+    use crate::graphs::extras::{c_n_graph, greene_sphere};
+
+    fn assert_projection<G: UGraph>(
+        cover: &CSRGraph,
+        projection: &VertGraphMap<'_, '_, CSRGraph, G>,
+    ) {
+        assert_eq!(&assert_reflexive_symmetric(cover), cover);
+        let mut workspace = vec![0; cover.n() as usize];
+        VertGraphMap::try_from(
+            Cow::Borrowed(cover),
+            Cow::Borrowed(projection.codomain()),
+            projection.mapped_vertices(),
+            &mut workspace,
+        )
+        .expect("projection should be a graph map");
+    }
+
+    #[test]
+    fn test_voltage_cover() {
+        let c5 = c_n_graph(5);
+        let (cover, projection) = voltage_cover(&c5, 3, &HashMap::new());
+        assert_projection(&cover, &projection);
+        assert_eq!(connected_components(&cover).len(), 3);
+
+        // Swapping the sheets along one edge unwinds C_5 to C_10
+        let voltages = HashMap::from([((0, 4), vec![1, 0])]);
+        let (cover, projection) = voltage_cover(&c5, 2, &voltages);
+        assert_projection(&cover, &projection);
+        assert_eq!(cover.n(), 10);
+        assert_eq!(connected_components(&cover).len(), 1);
+        assert!((0..10).all(|x| cover.degree(x) == 3));
+    }
+
+    #[test]
+    fn test_universal_cover_of_cycle() {
+        // The universal cover of C_5 is the infinite path
+        let c5 = c_n_graph(5);
+        let (cover, projection) = universal_cover(&c5, 2, 4);
+        assert_projection(&cover, &projection);
+        assert_eq!(cover.n(), 9);
+        assert_eq!(projection.map(0), 2);
+        let mut degrees = (0..9).map(|x| cover.degree(x)).collect::<Vec<_>>();
+        degrees.sort_unstable();
+        assert_eq!(degrees, [2, 2, 3, 3, 3, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_universal_cover_of_simply_connected() {
+        for graph in [c_n_graph(3), c_n_graph(4), greene_sphere()] {
+            let (cover, projection) = universal_cover(&graph, 0, 6);
+            assert_projection(&cover, &projection);
+            let mut images = projection.mapped_vertices().collect::<Vec<_>>();
+            images.sort_unstable();
+            assert_eq!(images, (0..graph.n()).collect::<Vec<_>>());
+        }
+    }
+}
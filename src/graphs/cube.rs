@@ -1,6 +1,6 @@
 use crate::graphs::{AdjMatrix, UGraph};
 use crate::prelude::*;
-use crate::shape::{Const, Dim};
+use crate::shape::{Const, Dim, StepDim};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CubeGraph<D: Dim> {
@@ -13,6 +13,22 @@ impl<D: Dim> CubeGraph<D> {
     }
 }
 
+impl<D: StepDim> CubeGraph<D> {
+    /// The cube one dimension lower, i.e. the domain of a face map
+    pub fn prev(&self) -> CubeGraph<D::Prev> {
+        CubeGraph {
+            dim: self.dim.prev(),
+        }
+    }
+
+    /// The cube one dimension higher, i.e. the domain of two glued maps
+    pub fn next(&self) -> CubeGraph<D::Next> {
+        CubeGraph {
+            dim: self.dim.next(),
+        }
+    }
+}
+
 impl<const N: u32> Default for CubeGraph<Const<N>> {
     fn default() -> Self {
         Self { dim: Const::<N> }
@@ -39,6 +55,7 @@ impl Newable<u32> for CubeGraph<u32> {
 }
 
 impl<D: Dim> From<CubeGraph<D>> for AdjMatrix {
+    #[allow(clippy::needless_range_loop)]
     fn from(value: CubeGraph<D>) -> Self {
         let n = value.dim.size();
         let verts = 2_usize.pow(n);
@@ -146,6 +163,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_into_adj_matrix() {
         let cube = CubeGraph::<Const<3>>::default();
         let n = cube.n() as usize;
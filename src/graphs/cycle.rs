@@ -0,0 +1,133 @@
+use crate::graphs::cube::Newable;
+use crate::graphs::{AdjMatrix, UGraph};
+use crate::prelude::*;
+use crate::shape::{Const, Dim};
+
+/// The cycle `C_n` on vertices `0..n`, with `i` adjacent to `i ± 1 mod n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleGraph<D: Dim> {
+    n: D,
+}
+
+impl<D: Dim> CycleGraph<D> {
+    pub fn size(&self) -> D {
+        self.n
+    }
+}
+
+impl<const N: u32> Default for CycleGraph<Const<N>> {
+    fn default() -> Self {
+        assert!(N >= 3, "cycles have at least 3 vertices");
+        Self { n: Const::<N> }
+    }
+}
+
+impl<const N: u32> Newable<Const<N>> for CycleGraph<Const<N>> {
+    fn new(n: Const<N>) -> Self {
+        assert!(N >= 3, "cycles have at least 3 vertices");
+        Self { n }
+    }
+}
+
+impl Newable<u32> for CycleGraph<u32> {
+    fn new(n: u32) -> Self {
+        assert!(n >= 3, "cycles have at least 3 vertices");
+        Self { n }
+    }
+}
+
+impl<D: Dim> GraphNeighbors for CycleGraph<D> {
+    fn neighbors(&self, v: u32) -> impl Iterator<Item = u32> {
+        let n = self.n.size();
+        let mut items = [(v + n - 1) % n, v, (v + 1) % n];
+        items.sort_unstable();
+        items.into_iter()
+    }
+}
+
+impl<D: Dim> UGraph for CycleGraph<D> {
+    fn n(&self) -> u32 {
+        self.n.size()
+    }
+
+    fn degree(&self, _v: u32) -> u32 {
+        3
+    }
+
+    fn is_edge<V: Into<u32>>(&self, a: V, b: V) -> bool {
+        cycle_share_edge(a.into(), b.into(), self.n.size())
+    }
+}
+
+/// Whether `a` and `b` are equal or adjacent in `C_n`
+pub fn cycle_share_edge(a: u32, b: u32, n: u32) -> bool {
+    let diff = a.abs_diff(b);
+    diff <= 1 || diff == n - 1
+}
+
+impl<D: Dim> From<CycleGraph<D>> for CSRGraph {
+    fn from(value: CycleGraph<D>) -> Self {
+        let n = value.n();
+        let offsets = (0..=n).map(|v| 3 * v).collect();
+        let neighbors = (0..n).flat_map(|v| value.neighbors(v)).collect();
+        CSRGraph::new(offsets, neighbors)
+    }
+}
+
+impl<D: Dim> From<CycleGraph<D>> for AdjMatrix {
+    fn from(value: CycleGraph<D>) -> Self {
+        CSRGraph::from(value).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_share_edge() {
+        assert!(cycle_share_edge(0, 4, 5));
+        assert!(cycle_share_edge(2, 2, 5));
+        assert!(cycle_share_edge(3, 2, 5));
+        assert!(!cycle_share_edge(0, 2, 5));
+        assert!(!cycle_share_edge(0, 4, 6));
+    }
+
+    #[test]
+    fn test_cycle_graph_matches_adjacency() {
+        for n in 3..12 {
+            let cycle = CycleGraph::<u32>::new(n);
+            let adj: AdjMatrix = (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| i == j || (i + 1) % n == j || (j + 1) % n == i)
+                        .collect()
+                })
+                .collect();
+            let expected = CSRGraph::try_from(adj).unwrap();
+            assert_eq!(CSRGraph::from(cycle), expected);
+            for v in 0..n {
+                assert!(cycle.neighbors(v).eq(expected.neighbors(v)));
+                assert_eq!(cycle.degree(v), expected.degree(v));
+                for w in 0..n {
+                    assert_eq!(cycle.is_edge(v, w), expected.is_edge(v, w));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_const_cycle() {
+        let c5 = CycleGraph::<Const<5>>::default();
+        assert_eq!(c5.n(), 5);
+        assert_eq!(
+            CSRGraph::from(c5),
+            CSRGraph::from(CycleGraph::<u32>::new(5))
+        );
+
+        // Large cycles are built without the quadratic adjacency matrix
+        let big = CSRGraph::from(CycleGraph::<u32>::new(100_000));
+        assert_eq!(big.n(), 100_000);
+        assert!(big.is_edge(0u32, 99_999));
+    }
+}
@@ -1,3 +1,6 @@
+use crate::graphs::cube::Newable;
+use crate::graphs::cycle::CycleGraph;
+use crate::graphs::families::GridGraph;
 use crate::prelude::*;
 pub fn greene_sphere() -> CSRGraph {
     let t = true;
@@ -17,21 +20,31 @@ pub fn greene_sphere() -> CSRGraph {
     CSRGraph::try_from(adj).unwrap()
 }
 
+/// The cycle `C_n` as a [CSRGraph], see [CycleGraph].
+///
+/// For `n < 3` the cycle degenerates to the complete graph on `n` vertices: the empty graph,
+/// a point and a single edge.
 pub fn c_n_graph(n: u32) -> CSRGraph {
-    let mut adj: AdjMatrix = vec![vec![false; n as usize]; n as usize];
-    for i in 0..n {
-        adj[i as usize][((i + 1) % n) as usize] = true;
-        adj[i as usize][((i + n - 1) % n) as usize] = true;
-        adj[i as usize][i as usize] = true;
+    if n < 3 {
+        return CSRGraph::from_neighbor_lists((0..n).map(|_| (0..n).collect()).collect());
     }
-    CSRGraph::try_from(adj).unwrap()
+    CycleGraph::<u32>::new(n).into()
+}
+
+/// The grid graph `I_m^n`: vertices are points of `{0, ..., m}^n`, encoded as
+/// `x_0 + (m + 1) x_1 + ... + (m + 1)^(n - 1) x_(n - 1)`, adjacent when they differ by one in
+/// a single coordinate. See [GridGraph] for grids with unequal sides.
+pub fn grid_graph(m: u32, n: u32) -> CSRGraph {
+    GridGraph::uniform(m, n).into()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graphs::cube::{CubeGraph, Newable};
 
     #[test]
+    #[allow(clippy::unnecessary_cast)]
     fn test_greene_sphere() {
         let greene_sphere = greene_sphere();
         let n = greene_sphere.n();
@@ -58,6 +71,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_grid_graph() {
+        let grid = grid_graph(2, 2);
+        assert_eq!(grid.n(), 9);
+        // Corner, edge midpoint and center of the 3x3 grid
+        assert_eq!(grid.neighbors(0).collect::<Vec<_>>(), vec![0, 1, 3]);
+        assert_eq!(grid.neighbors(1).collect::<Vec<_>>(), vec![0, 1, 2, 4]);
+        assert_eq!(grid.neighbors(4).collect::<Vec<_>>(), vec![1, 3, 4, 5, 7]);
+
+        // I_1^n is the n-cube
+        let cube_adj: AdjMatrix = CubeGraph::new(3).into();
+        let grid_adj: AdjMatrix = grid_graph(1, 3).into();
+        assert_eq!(cube_adj, grid_adj);
+
+        assert_eq!(grid_graph(4, 1), {
+            let path: AdjMatrix = (0..5)
+                .map(|i: i32| (0..5).map(|j: i32| (i - j).abs() <= 1).collect())
+                .collect();
+            CSRGraph::try_from(path).unwrap()
+        });
+    }
+
     #[test]
     fn test_c_n_graph() {
         // Test C_3 (triangle cycle)
@@ -137,4 +172,19 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_c_n_graph_small() {
+        assert_eq!(c_n_graph(0).n(), 0);
+
+        let point = c_n_graph(1);
+        assert_eq!(point.n(), 1);
+        assert_eq!(point.neighbors(0u32).collect::<Vec<_>>(), vec![0]);
+
+        let edge = c_n_graph(2);
+        assert_eq!(edge.n(), 2);
+        for v in 0u32..2 {
+            assert_eq!(edge.neighbors(v).collect::<Vec<_>>(), vec![0, 1]);
+        }
+    }
 }
@@ -0,0 +1,394 @@
+//! Implicit graphs of standard families, computing adjacency from vertex labels instead of
+//! storing it, like [CubeGraph](crate::graphs::cube::CubeGraph).
+
+use crate::prelude::*;
+
+/// The path `I_n` on vertices `0..=n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathGraph {
+    length: u32,
+}
+
+impl PathGraph {
+    pub fn new(length: u32) -> Self {
+        Self { length }
+    }
+
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+}
+
+impl GraphNeighbors for PathGraph {
+    fn neighbors(&self, v: u32) -> impl Iterator<Item = u32> {
+        v.saturating_sub(1)..=(v + 1).min(self.length)
+    }
+}
+
+impl UGraph for PathGraph {
+    fn n(&self) -> u32 {
+        self.length + 1
+    }
+
+    fn degree(&self, v: u32) -> u32 {
+        1 + (v > 0) as u32 + (v < self.length) as u32
+    }
+
+    fn is_edge<V: Into<u32>>(&self, a: V, b: V) -> bool {
+        a.into().abs_diff(b.into()) <= 1
+    }
+}
+
+impl From<PathGraph> for AdjMatrix {
+    fn from(value: PathGraph) -> Self {
+        adjacency_matrix(&value)
+    }
+}
+
+/// The grid `I_(m_0) × ... × I_(m_(d-1))`, with vertex `x` encoded as
+/// `x_0 + (m_0 + 1) x_1 + (m_0 + 1)(m_1 + 1) x_2 + ...`, matching
+/// [grid_graph](crate::graphs::extras::grid_graph) when all sides are equal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridGraph {
+    sides: Vec<u32>,
+    strides: Vec<u32>,
+}
+
+impl GridGraph {
+    pub fn new(sides: Vec<u32>) -> Self {
+        let strides = mixed_radix_strides(sides.iter().map(|&m| m + 1));
+        Self { sides, strides }
+    }
+
+    /// The grid `I_m^dim`
+    pub fn uniform(m: u32, dim: u32) -> Self {
+        Self::new(vec![m; dim as usize])
+    }
+
+    pub fn sides(&self) -> &[u32] {
+        &self.sides
+    }
+
+    /// The coordinates of vertex `v`
+    pub fn coordinates(&self, v: u32) -> Vec<u32> {
+        self.sides
+            .iter()
+            .zip(&self.strides)
+            .map(|(&m, &stride)| v / stride % (m + 1))
+            .collect()
+    }
+
+    /// Whether vertex `v` has some coordinate equal to 0 or to the side in that coordinate
+    pub fn is_boundary(&self, v: u32) -> bool {
+        self.sides.iter().zip(&self.strides).any(|(&m, &stride)| {
+            let x = v / stride % (m + 1);
+            x == 0 || x == m
+        })
+    }
+}
+
+impl GraphNeighbors for GridGraph {
+    fn neighbors(&self, v: u32) -> impl Iterator<Item = u32> {
+        // Decreasing a coordinate, from the largest stride down, then v itself, then increasing
+        // a coordinate, from the smallest stride up, keeps the neighbours sorted
+        let coordinate = move |i: usize| v / self.strides[i] % (self.sides[i] + 1);
+        let down = (0..self.sides.len())
+            .rev()
+            .filter(move |&i| coordinate(i) > 0)
+            .map(move |i| v - self.strides[i]);
+        let up = (0..self.sides.len())
+            .filter(move |&i| coordinate(i) < self.sides[i])
+            .map(move |i| v + self.strides[i]);
+        down.chain(std::iter::once(v)).chain(up)
+    }
+}
+
+impl UGraph for GridGraph {
+    fn n(&self) -> u32 {
+        self.sides.iter().map(|&m| m + 1).product()
+    }
+
+    fn degree(&self, v: u32) -> u32 {
+        1 + self
+            .coordinates(v)
+            .iter()
+            .zip(&self.sides)
+            .map(|(&x, &m)| (x > 0) as u32 + (x < m) as u32)
+            .sum::<u32>()
+    }
+
+    /// A step adds one stride to the lower end, without carrying out of its coordinate, so
+    /// only the coordinate of the largest stride below the difference is decoded: `O(log d)`
+    /// for the binary search over the `d` strides.
+    fn is_edge<V: Into<u32>>(&self, a: V, b: V) -> bool {
+        let (a, b) = (a.into(), b.into());
+        let (low, diff) = (a.min(b), a.abs_diff(b));
+        if diff == 0 {
+            return true;
+        }
+        // Sides of 0 repeat a stride, the last of them is the coordinate that can move
+        let i = self.strides.partition_point(|&stride| stride <= diff) - 1;
+        let (m, stride) = (self.sides[i], self.strides[i]);
+        diff == stride && low / stride % (m + 1) < m
+    }
+}
+
+impl From<GridGraph> for CSRGraph {
+    fn from(value: GridGraph) -> Self {
+        CSRGraph::from_graph(&value)
+    }
+}
+
+impl From<GridGraph> for AdjMatrix {
+    fn from(value: GridGraph) -> Self {
+        adjacency_matrix(&value)
+    }
+}
+
+/// The discrete torus `C_(n_0) □ ... □ C_(n_(d-1))`, with vertex `x` encoded as
+/// `x_0 + n_0 x_1 + n_0 n_1 x_2 + ...`, each cycle labelled as by
+/// [c_n_graph](crate::graphs::extras::c_n_graph).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TorusGraph {
+    sizes: Vec<u32>,
+    strides: Vec<u32>,
+}
+
+impl TorusGraph {
+    pub fn new(sizes: Vec<u32>) -> Self {
+        assert!(
+            sizes.iter().all(|&n| n >= 3),
+            "cycles have at least 3 vertices"
+        );
+        let strides = mixed_radix_strides(sizes.iter().copied());
+        Self { sizes, strides }
+    }
+
+    pub fn sizes(&self) -> &[u32] {
+        &self.sizes
+    }
+}
+
+impl GraphNeighbors for TorusGraph {
+    fn neighbors(&self, v: u32) -> impl Iterator<Item = u32> {
+        // As for GridGraph, except that wrapping around a cycle moves (n - 1) strides, which is
+        // further than a single step but still less than the next stride
+        let step = move |i: usize| (v / self.strides[i] % self.sizes[i], self.sizes[i] - 1);
+        let down = (0..self.sizes.len()).rev().flat_map(move |i| {
+            let ((x, last), stride) = (step(i), self.strides[i]);
+            let wrap = (x == last).then(|| v - last * stride);
+            let back = (x > 0).then(|| v - stride);
+            wrap.into_iter().chain(back)
+        });
+        let up = (0..self.sizes.len()).flat_map(move |i| {
+            let ((x, last), stride) = (step(i), self.strides[i]);
+            let forward = (x < last).then(|| v + stride);
+            let wrap = (x == 0).then(|| v + last * stride);
+            forward.into_iter().chain(wrap)
+        });
+        down.chain(std::iter::once(v)).chain(up)
+    }
+}
+
+impl UGraph for TorusGraph {
+    fn n(&self) -> u32 {
+        self.sizes.iter().product()
+    }
+
+    fn degree(&self, _v: u32) -> u32 {
+        1 + 2 * self.sizes.len() as u32
+    }
+
+    /// As for [GridGraph], a step moves one coordinate of the lower end by one stride, or by
+    /// `n - 1` strides when wrapping around from 0, so this is `O(log d)` as well.
+    fn is_edge<V: Into<u32>>(&self, a: V, b: V) -> bool {
+        let (a, b) = (a.into(), b.into());
+        let (low, diff) = (a.min(b), a.abs_diff(b));
+        if diff == 0 {
+            return true;
+        }
+        let i = self.strides.partition_point(|&stride| stride <= diff) - 1;
+        let (n, stride) = (self.sizes[i], self.strides[i]);
+        let x = low / stride % n;
+        (diff == stride && x < n - 1) || (diff == (n - 1) * stride && x == 0)
+    }
+}
+
+impl From<TorusGraph> for AdjMatrix {
+    fn from(value: TorusGraph) -> Self {
+        adjacency_matrix(&value)
+    }
+}
+
+/// The reflexive complete graph `K_n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompleteGraph {
+    n: u32,
+}
+
+impl CompleteGraph {
+    pub fn new(n: u32) -> Self {
+        Self { n }
+    }
+}
+
+impl GraphNeighbors for CompleteGraph {
+    fn neighbors(&self, _v: u32) -> impl Iterator<Item = u32> {
+        0..self.n
+    }
+}
+
+impl UGraph for CompleteGraph {
+    fn n(&self) -> u32 {
+        self.n
+    }
+
+    fn degree(&self, _v: u32) -> u32 {
+        self.n
+    }
+
+    fn is_edge<V: Into<u32>>(&self, _a: V, _b: V) -> bool {
+        true
+    }
+}
+
+impl From<CompleteGraph> for AdjMatrix {
+    fn from(value: CompleteGraph) -> Self {
+        adjacency_matrix(&value)
+    }
+}
+
+/// The Hamming graph `H(n, q)`: words of length `n` over `q` letters, encoded in base `q`
+/// with the first letter least significant, adjacent when they differ in a single letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HammingGraph {
+    length: u32,
+    q: u32,
+}
+
+impl HammingGraph {
+    pub fn new(length: u32, q: u32) -> Self {
+        assert!(q >= 1, "the alphabet must not be empty");
+        Self { length, q }
+    }
+}
+
+impl GraphNeighbors for HammingGraph {
+    fn neighbors(&self, v: u32) -> impl Iterator<Item = u32> {
+        // Changing letter i moves less than q^(i + 1), so the smaller letters at the last
+        // position come first and the larger letters at the first position come right after v
+        let q = self.q;
+        let letter = move |i: u32| {
+            let stride = q.pow(i);
+            (v / stride % q, stride)
+        };
+        let down = (0..self.length).rev().flat_map(move |i| {
+            let (x, stride) = letter(i);
+            (0..x).map(move |a| v - (x - a) * stride)
+        });
+        let up = (0..self.length).flat_map(move |i| {
+            let (x, stride) = letter(i);
+            (x + 1..q).map(move |a| v + (a - x) * stride)
+        });
+        down.chain(std::iter::once(v)).chain(up)
+    }
+}
+
+impl UGraph for HammingGraph {
+    fn n(&self) -> u32 {
+        self.q.pow(self.length)
+    }
+
+    fn degree(&self, _v: u32) -> u32 {
+        1 + self.length * (self.q - 1)
+    }
+
+    /// Words differing in letter `i` alone differ by less than `q^(i + 1)` and by at least
+    /// `q^i`, so the letter is read off the difference and the rest compared in `O(1)`.
+    fn is_edge<V: Into<u32>>(&self, a: V, b: V) -> bool {
+        let (a, b) = (a.into(), b.into());
+        if a == b {
+            return true;
+        }
+        let stride = self.q.pow(a.abs_diff(b).ilog(self.q));
+        a % stride == b % stride && a / stride / self.q == b / stride / self.q
+    }
+}
+
+impl From<HammingGraph> for AdjMatrix {
+    fn from(value: HammingGraph) -> Self {
+        adjacency_matrix(&value)
+    }
+}
+
+fn mixed_radix_strides(radices: impl Iterator<Item = u32>) -> Vec<u32> {
+    let mut stride = 1;
+    radices
+        .map(|radix| {
+            let current = stride;
+            stride *= radix;
+            current
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::cube::{CubeGraph, Newable};
+    use crate::graphs::extras::{c_n_graph, grid_graph};
+    use crate::graphs::product::BoxProduct;
+
+    #[test]
+    fn test_paths_and_grids() {
+        assert_eq!(
+            assert_reflexive_symmetric(&PathGraph::new(4)),
+            grid_graph(4, 1)
+        );
+        assert_eq!(assert_reflexive_symmetric(&PathGraph::new(0)).n(), 1);
+        assert_eq!(
+            assert_reflexive_symmetric(&GridGraph::uniform(2, 2)),
+            grid_graph(2, 2)
+        );
+        assert_eq!(
+            AdjMatrix::from(GridGraph::uniform(1, 3)),
+            AdjMatrix::from(CubeGraph::<u32>::new(3))
+        );
+
+        let grid = GridGraph::new(vec![3, 1, 2]);
+        assert_eq!(grid.n(), 24);
+        assert_reflexive_symmetric(&grid);
+        assert_eq!(grid.coordinates(4 + 8 + 3), vec![3, 1, 1]);
+        // Sides of 0 repeat a stride
+        assert_reflexive_symmetric(&GridGraph::new(vec![0, 2, 0, 1, 0]));
+    }
+
+    #[test]
+    fn test_tori() {
+        let torus = TorusGraph::new(vec![5, 5]);
+        let product = BoxProduct::new(c_n_graph(5), c_n_graph(5));
+        assert_eq!(
+            assert_reflexive_symmetric(&torus),
+            assert_reflexive_symmetric(&product)
+        );
+        assert_eq!(
+            assert_reflexive_symmetric(&TorusGraph::new(vec![6])),
+            c_n_graph(6)
+        );
+        assert_reflexive_symmetric(&TorusGraph::new(vec![3, 4, 5]));
+    }
+
+    #[test]
+    fn test_complete_and_hamming() {
+        let k5 = assert_reflexive_symmetric(&CompleteGraph::new(5));
+        assert_eq!(k5, CSRGraph::try_from(vec![vec![true; 5]; 5]).unwrap());
+        assert_eq!(assert_reflexive_symmetric(&HammingGraph::new(1, 5)), k5);
+        assert_eq!(
+            AdjMatrix::from(HammingGraph::new(4, 2)),
+            AdjMatrix::from(CubeGraph::<u32>::new(4))
+        );
+        let hamming = HammingGraph::new(3, 3);
+        assert_eq!(hamming.n(), 27);
+        assert_reflexive_symmetric(&hamming);
+    }
+}
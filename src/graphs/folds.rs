@@ -0,0 +1,162 @@
+use std::borrow::Cow;
+
+use crate::graph_maps::VertGraphMap;
+use crate::graphs::subgraph::InducedSubgraph;
+use crate::prelude::*;
+
+/// Repeatedly removes a vertex `v` whose closed neighbourhood is contained in that of another
+/// remaining vertex `w`, folding `v` onto `w`, until no such vertex is left.
+///
+/// Folds preserve the discrete homotopy type. Returns the reduced graph on the remaining
+/// vertices (relabelled in increasing order), the retraction `graph -> reduced` sending each
+/// folded vertex to where it was folded, and the inclusion `reduced -> graph`.
+#[allow(clippy::type_complexity)]
+pub fn fold_dominated<G: UGraph>(
+    graph: &G,
+) -> (
+    CSRGraph,
+    VertGraphMap<'_, 'static, G, CSRGraph>,
+    VertGraphMap<'static, '_, CSRGraph, G>,
+) {
+    let n = graph.n() as usize;
+    let mut alive = vec![true; n];
+    // Vertex each folded vertex was folded onto
+    let mut target: Vec<u32> = (0..n as u32).collect();
+
+    let mut folded = true;
+    while folded {
+        folded = false;
+        for v in 0..n as u32 {
+            if !alive[v as usize] {
+                continue;
+            }
+            let closed = graph
+                .neighbors(v)
+                .filter(|&x| alive[x as usize])
+                .collect::<Vec<_>>();
+            let dominating = closed
+                .iter()
+                .copied()
+                .find(|&w| w != v && closed.iter().all(|&x| graph.is_edge(w, x)));
+            if let Some(w) = dominating {
+                alive[v as usize] = false;
+                target[v as usize] = w;
+                folded = true;
+            }
+        }
+    }
+
+    let kept = (0..n as u32)
+        .filter(|&v| alive[v as usize])
+        .collect::<Vec<_>>();
+    let mut relabel = vec![0u32; n];
+    for (i, &v) in kept.iter().enumerate() {
+        relabel[v as usize] = i as u32;
+    }
+    let retraction = (0..n)
+        .map(|v| {
+            let mut v = v;
+            while !alive[v] {
+                v = target[v] as usize;
+            }
+            relabel[v]
+        })
+        .collect::<Vec<_>>();
+
+    let reduced = CSRGraph::from_graph(&InducedSubgraph::new(graph, kept.iter().copied()));
+
+    // Safety: folding v onto w is a graph map since N[v] is contained in N[w], and the
+    // retraction is a composite of folds. The inclusion of an induced subgraph is a graph map.
+    let (retraction, inclusion) = unsafe {
+        (
+            VertGraphMap::new_unchecked(
+                Cow::Borrowed(graph),
+                Cow::Owned(reduced.clone()),
+                Cow::Owned(retraction),
+            ),
+            VertGraphMap::new_unchecked(
+                Cow::Owned(reduced.clone()),
+                Cow::Borrowed(graph),
+                Cow::Owned(kept),
+            ),
+        )
+    };
+    (reduced, retraction, inclusion)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::GraphMap;
+    use crate::graphs::extras::{c_n_graph, greene_sphere, grid_graph};
+    use crate::homology::{betti_numbers, CubeComplex};
+
+    fn assert_valid_maps<G: UGraph>(
+        graph: &G,
+        reduced: &CSRGraph,
+        retraction: &VertGraphMap<'_, '_, G, CSRGraph>,
+        inclusion: &VertGraphMap<'_, '_, CSRGraph, G>,
+    ) {
+        let mut workspace = vec![0; graph.n() as usize];
+        VertGraphMap::try_from(
+            Cow::Borrowed(graph),
+            Cow::Borrowed(reduced),
+            retraction.mapped_vertices(),
+            &mut workspace,
+        )
+        .expect("retraction should be a graph map");
+        VertGraphMap::try_from(
+            Cow::Borrowed(reduced),
+            Cow::Borrowed(graph),
+            inclusion.mapped_vertices(),
+            &mut workspace,
+        )
+        .expect("inclusion should be a graph map");
+        for v in 0..reduced.n() {
+            assert_eq!(retraction.map(inclusion.map(v)), v);
+        }
+    }
+
+    #[test]
+    fn test_fold_to_point() {
+        let path = grid_graph(5, 1);
+        let (reduced, retraction, inclusion) = fold_dominated(&path);
+        assert_eq!(reduced.n(), 1);
+        assert_valid_maps(&path, &reduced, &retraction, &inclusion);
+
+        let complete = CSRGraph::try_from(vec![vec![true; 6]; 6]).unwrap();
+        let (reduced, _, _) = fold_dominated(&complete);
+        assert_eq!(reduced.n(), 1);
+    }
+
+    #[test]
+    fn test_stiff_graphs_unchanged() {
+        for graph in [c_n_graph(4), c_n_graph(7), greene_sphere()] {
+            let (reduced, retraction, inclusion) = fold_dominated(&graph);
+            assert_eq!(reduced, graph);
+            assert_valid_maps(&graph, &reduced, &retraction, &inclusion);
+        }
+    }
+
+    #[test]
+    fn test_fold_preserves_homology() {
+        // C_5 with a triangle glued on the edge 0 - 1 and a path 5 - 6 - 7 hanging off it
+        let mut adj: AdjMatrix = c_n_graph(5).into();
+        adj.iter_mut().for_each(|row| row.resize(8, false));
+        adj.resize(8, vec![false; 8]);
+        for (a, b) in [(5, 0), (5, 1), (5, 6), (6, 7)] {
+            adj[a][b] = true;
+            adj[b][a] = true;
+        }
+        (5..8).for_each(|i| adj[i][i] = true);
+        let graph = CSRGraph::try_from(adj).unwrap();
+
+        let (reduced, retraction, inclusion) = fold_dominated(&graph);
+        assert_eq!(reduced, c_n_graph(5));
+        assert_valid_maps(&graph, &reduced, &retraction, &inclusion);
+        assert_eq!(
+            betti_numbers(&graph, 1, CubeComplex::Normalized),
+            betti_numbers(&reduced, 1, CubeComplex::Normalized)
+        );
+    }
+}
@@ -1,5 +1,16 @@
+pub mod cayley;
+pub mod covers;
 pub mod cube;
+pub mod cycle;
 pub mod extras;
+pub mod families;
+pub mod folds;
+pub mod product;
+pub mod quotient;
+pub mod spheres;
+pub mod subgraph;
+pub mod sums;
+pub mod suspension;
 use std::convert::TryFrom;
 use std::fmt::Debug;
 pub type AdjMatrix = Vec<Vec<bool>>;
@@ -50,6 +61,7 @@ pub struct CSRGraph {
 
 impl TryFrom<AdjMatrix> for CSRGraph {
     type Error = String;
+    #[allow(clippy::needless_range_loop)]
     fn try_from(mat: AdjMatrix) -> Result<Self, Self::Error> {
         let n: usize = mat.len();
         if n == 0 {
@@ -179,6 +191,92 @@ impl CSRGraph {
             neighbor_list: neighbors,
         }
     }
+
+    /// Copies a graph from its neighbour iterators, without going through an [AdjMatrix].
+    /// Lists that do not come out sorted are sorted, the implicit graphs need no sorting.
+    pub(crate) fn from_graph<G: UGraph>(graph: &G) -> Self {
+        let mut offsets = vec![0u32];
+        let mut neighbor_list = Vec::new();
+        for v in 0..graph.n() {
+            let start = neighbor_list.len();
+            neighbor_list.extend(graph.neighbors(v));
+            if !neighbor_list[start..].is_sorted() {
+                neighbor_list[start..].sort_unstable();
+            }
+            offsets.push(neighbor_list.len() as u32);
+        }
+        Self::new(offsets, neighbor_list)
+    }
+
+    /// Builds a graph from unsorted neighbour lists, which may contain duplicates.
+    pub(crate) fn from_neighbor_lists(adjacent: Vec<Vec<u32>>) -> Self {
+        let mut offsets = vec![0u32];
+        let mut neighbor_list = Vec::new();
+        for mut neighbors in adjacent {
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbor_list.extend(neighbors);
+            offsets.push(neighbor_list.len() as u32);
+        }
+        Self::new(offsets, neighbor_list)
+    }
+}
+
+/// The adjacency matrix of a graph, read off its neighbour iterators. Implicit graphs use
+/// this for their [AdjMatrix] conversion.
+pub(crate) fn adjacency_matrix<G: UGraph>(graph: &G) -> AdjMatrix {
+    let n = graph.n() as usize;
+    let mut adj: AdjMatrix = vec![vec![false; n]; n];
+    for (v, row) in adj.iter_mut().enumerate() {
+        for w in graph.neighbors(v as u32) {
+            row[w as usize] = true;
+        }
+    }
+    adj
+}
+
+/// Checks `graph` is reflexive and symmetric, by converting it to a [CSRGraph], and that its
+/// `neighbors`, `degree` and `is_edge` agree with that conversion, which is returned.
+#[cfg(test)]
+pub(crate) fn assert_reflexive_symmetric<G: UGraph>(graph: &G) -> CSRGraph {
+    let adj: AdjMatrix = graph.clone().into();
+    let csr = CSRGraph::try_from(adj).expect("graph should be reflexive and symmetric");
+    for v in 0..graph.n() {
+        assert!(graph.neighbors(v).eq(csr.neighbors(v)), "neighbours of {v}");
+        assert_eq!(graph.degree(v), csr.degree(v), "degree of {v}");
+        for w in 0..graph.n() {
+            assert_eq!(graph.is_edge(v, w), csr.is_edge(v, w), "edge {v} - {w}");
+        }
+    }
+    csr
+}
+
+/// Connected components of `graph`, each sorted, ordered by their smallest vertex.
+pub fn connected_components<G: UGraph>(graph: &G) -> Vec<Vec<u32>> {
+    let n = graph.n() as usize;
+    let mut seen = vec![false; n];
+    let mut components = Vec::new();
+    for start in 0..n {
+        if seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        let mut component = vec![start as u32];
+        let mut head = 0;
+        while head < component.len() {
+            let v = component[head];
+            head += 1;
+            for w in graph.neighbors(v) {
+                if !seen[w as usize] {
+                    seen[w as usize] = true;
+                    component.push(w);
+                }
+            }
+        }
+        component.sort_unstable();
+        components.push(component);
+    }
+    components
 }
 
 #[cfg(test)]
@@ -314,6 +412,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_connected_components() {
+        let t = true;
+        let f = false;
+        // Path 0 - 2 - 4, edge 1 - 3, isolated 5
+        let mat: AdjMatrix = vec![
+            vec![t, f, t, f, f, f],
+            vec![f, t, f, t, f, f],
+            vec![t, f, t, f, t, f],
+            vec![f, t, f, t, f, f],
+            vec![f, f, t, f, t, f],
+            vec![f, f, f, f, f, t],
+        ];
+        let graph = CSRGraph::try_from(mat).unwrap();
+        assert_eq!(
+            connected_components(&graph),
+            vec![vec![0, 2, 4], vec![1, 3], vec![5]]
+        );
+        assert!(connected_components(&CSRGraph::try_from(vec![]).unwrap()).is_empty());
+    }
+
     #[test]
     fn test_tryfrom_empty_matrix() {
         // Test edge case: empty matrix
@@ -0,0 +1,384 @@
+use std::borrow::Cow;
+
+use crate::graph_maps::{GraphMap, VertGraphMap};
+use crate::prelude::*;
+
+/// The box (Cartesian) product `G □ H`: `(g, h)` is adjacent to `(g', h)` for `g'` adjacent
+/// to `g` and to `(g, h')` for `h'` adjacent to `h`. Vertex `(g, h)` is `g * |H| + h`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoxProduct<G: UGraph, H: UGraph> {
+    left: G,
+    right: H,
+}
+
+impl<G: UGraph, H: UGraph> BoxProduct<G, H> {
+    pub fn new(left: G, right: H) -> Self {
+        Self { left, right }
+    }
+
+    pub fn left(&self) -> &G {
+        &self.left
+    }
+
+    pub fn right(&self) -> &H {
+        &self.right
+    }
+
+    /// The vertex `(g, h)`
+    pub fn pair(&self, g: u32, h: u32) -> u32 {
+        g * self.right.n() + h
+    }
+
+    /// The factors `(g, h)` of vertex `v`
+    pub fn split(&self, v: u32) -> (u32, u32) {
+        (v / self.right.n(), v % self.right.n())
+    }
+
+    /// The projection `(g, h) -> g`
+    pub fn left_projection(&self) -> VertGraphMap<'_, '_, Self, G> {
+        let images = (0..self.n()).map(|v| self.split(v).0).collect();
+        // Safety: both kinds of edges project to an edge or a vertex
+        unsafe {
+            VertGraphMap::new_unchecked(
+                Cow::Borrowed(self),
+                Cow::Borrowed(&self.left),
+                Cow::Owned(images),
+            )
+        }
+    }
+
+    /// The projection `(g, h) -> h`
+    pub fn right_projection(&self) -> VertGraphMap<'_, '_, Self, H> {
+        let images = (0..self.n()).map(|v| self.split(v).1).collect();
+        // Safety: both kinds of edges project to an edge or a vertex
+        unsafe {
+            VertGraphMap::new_unchecked(
+                Cow::Borrowed(self),
+                Cow::Borrowed(&self.right),
+                Cow::Owned(images),
+            )
+        }
+    }
+}
+
+impl<G: UGraph, H: UGraph> GraphNeighbors for BoxProduct<G, H> {
+    fn neighbors(&self, v: u32) -> impl Iterator<Item = u32> {
+        // The neighbours in the copy of H at g, v included, lie between those in the copies
+        // at smaller and at larger neighbours of g, so this is sorted when the factors are
+        let (g, h) = self.split(v);
+        let below = self
+            .left
+            .neighbors(g)
+            .filter(move |&g2| g2 < g)
+            .map(move |g2| self.pair(g2, h));
+        let level = self.right.neighbors(h).map(move |h2| self.pair(g, h2));
+        let above = self
+            .left
+            .neighbors(g)
+            .filter(move |&g2| g2 > g)
+            .map(move |g2| self.pair(g2, h));
+        below.chain(level).chain(above)
+    }
+}
+
+impl<G: UGraph, H: UGraph> UGraph for BoxProduct<G, H> {
+    fn n(&self) -> u32 {
+        self.left.n() * self.right.n()
+    }
+
+    fn degree(&self, v: u32) -> u32 {
+        let (g, h) = self.split(v);
+        (self.left.neighbors(g).count() + self.right.neighbors(h).count() - 1) as u32
+    }
+
+    fn is_edge<V: Into<u32>>(&self, a: V, b: V) -> bool {
+        let (ga, ha) = self.split(a.into());
+        let (gb, hb) = self.split(b.into());
+        (ga == gb && self.right.is_edge(ha, hb)) || (ha == hb && self.left.is_edge(ga, gb))
+    }
+}
+
+impl<G: UGraph, H: UGraph> From<BoxProduct<G, H>> for AdjMatrix {
+    fn from(value: BoxProduct<G, H>) -> Self {
+        adjacency_matrix(&value)
+    }
+}
+
+/// The strong product `G ⊠ H`: `(g, h)` is adjacent to `(g', h')` when `g` is adjacent or
+/// equal to `g'` and `h` to `h'`. Vertex `(g, h)` is `g * |H| + h`.
+///
+/// Since graphs here are reflexive, this is also the tensor product and the categorical
+/// product: maps `U -> G ⊠ H` are exactly pairs of maps, see [StrongProduct::pair_maps].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrongProduct<G: UGraph, H: UGraph> {
+    left: G,
+    right: H,
+}
+
+/// The tensor product, which coincides with [StrongProduct] for reflexive graphs
+pub type TensorProduct<G, H> = StrongProduct<G, H>;
+
+impl<G: UGraph, H: UGraph> StrongProduct<G, H> {
+    pub fn new(left: G, right: H) -> Self {
+        Self { left, right }
+    }
+
+    pub fn left(&self) -> &G {
+        &self.left
+    }
+
+    pub fn right(&self) -> &H {
+        &self.right
+    }
+
+    /// The vertex `(g, h)`
+    pub fn pair(&self, g: u32, h: u32) -> u32 {
+        g * self.right.n() + h
+    }
+
+    /// The factors `(g, h)` of vertex `v`
+    pub fn split(&self, v: u32) -> (u32, u32) {
+        (v / self.right.n(), v % self.right.n())
+    }
+
+    /// The projection `(g, h) -> g`
+    pub fn left_projection(&self) -> VertGraphMap<'_, '_, Self, G> {
+        let images = (0..self.n()).map(|v| self.split(v).0).collect();
+        // Safety: adjacent pairs have adjacent or equal factors
+        unsafe {
+            VertGraphMap::new_unchecked(
+                Cow::Borrowed(self),
+                Cow::Borrowed(&self.left),
+                Cow::Owned(images),
+            )
+        }
+    }
+
+    /// The projection `(g, h) -> h`
+    pub fn right_projection(&self) -> VertGraphMap<'_, '_, Self, H> {
+        let images = (0..self.n()).map(|v| self.split(v).1).collect();
+        // Safety: adjacent pairs have adjacent or equal factors
+        unsafe {
+            VertGraphMap::new_unchecked(
+                Cow::Borrowed(self),
+                Cow::Borrowed(&self.right),
+                Cow::Owned(images),
+            )
+        }
+    }
+
+    /// The map `u -> (f(u), g(u))` induced by `f: U -> G` and `g: U -> H`.
+    pub fn pair_maps<'u, U: UGraph>(
+        &self,
+        f: &'u impl GraphMap<U, G>,
+        g: &impl GraphMap<U, H>,
+    ) -> VertGraphMap<'u, '_, U, Self> {
+        assert!(f.domain() == g.domain());
+        assert!(f.codomain() == &self.left && g.codomain() == &self.right);
+        let images = f
+            .mapped_vertices()
+            .zip(g.mapped_vertices())
+            .map(|(x, y)| self.pair(x, y))
+            .collect();
+        // Safety: each factor of an edge is an edge, so the pair is an edge
+        unsafe {
+            VertGraphMap::new_unchecked(
+                Cow::Borrowed(f.domain()),
+                Cow::Borrowed(self),
+                Cow::Owned(images),
+            )
+        }
+    }
+}
+
+impl<G: UGraph, H: UGraph> GraphNeighbors for StrongProduct<G, H> {
+    fn neighbors(&self, v: u32) -> impl Iterator<Item = u32> {
+        // Pairs in lexicographic order, so sorted when the factors are, as `g` is the more
+        // significant digit
+        let (g, h) = self.split(v);
+        self.left
+            .neighbors(g)
+            .flat_map(move |g2| self.right.neighbors(h).map(move |h2| self.pair(g2, h2)))
+    }
+}
+
+impl<G: UGraph, H: UGraph> UGraph for StrongProduct<G, H> {
+    fn n(&self) -> u32 {
+        self.left.n() * self.right.n()
+    }
+
+    fn degree(&self, v: u32) -> u32 {
+        let (g, h) = self.split(v);
+        (self.left.neighbors(g).count() * self.right.neighbors(h).count()) as u32
+    }
+
+    fn is_edge<V: Into<u32>>(&self, a: V, b: V) -> bool {
+        let (ga, ha) = self.split(a.into());
+        let (gb, hb) = self.split(b.into());
+        self.left.is_edge(ga, gb) && self.right.is_edge(ha, hb)
+    }
+}
+
+impl<G: UGraph, H: UGraph> From<StrongProduct<G, H>> for AdjMatrix {
+    fn from(value: StrongProduct<G, H>) -> Self {
+        adjacency_matrix(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::compose;
+    use crate::graphs::cube::{CubeGraph, Newable};
+    use crate::graphs::extras::c_n_graph;
+    use crate::homology::{betti_numbers, CubeComplex};
+
+    #[test]
+    fn test_cube_is_box_product() {
+        let k2 = CubeGraph::<u32>::new(1);
+        let square = BoxProduct::new(k2, k2);
+        assert_eq!(
+            AdjMatrix::from(square.clone()),
+            AdjMatrix::from(CubeGraph::<u32>::new(2))
+        );
+        let cube = BoxProduct::new(square, k2);
+        assert_eq!(
+            AdjMatrix::from(cube.clone()),
+            AdjMatrix::from(CubeGraph::<u32>::new(3))
+        );
+        for v in 0..cube.n() {
+            assert_eq!(cube.degree(v), cube.neighbors(v).count() as u32);
+            for w in 0..cube.n() {
+                assert_eq!(cube.is_edge(v, w), cube.neighbors(v).any(|x| x == w));
+            }
+        }
+    }
+
+    /// A cycle listing its neighbours in decreasing order
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Reversed(CSRGraph);
+
+    impl GraphNeighbors for Reversed {
+        fn neighbors(&self, v: u32) -> impl Iterator<Item = u32> {
+            self.0.neighbors(v).collect::<Vec<_>>().into_iter().rev()
+        }
+    }
+
+    impl UGraph for Reversed {
+        fn n(&self) -> u32 {
+            self.0.n()
+        }
+
+        fn degree(&self, v: u32) -> u32 {
+            self.0.degree(v)
+        }
+
+        fn is_edge<V: Into<u32>>(&self, a: V, b: V) -> bool {
+            self.0.is_edge(a, b)
+        }
+    }
+
+    impl From<Reversed> for AdjMatrix {
+        fn from(value: Reversed) -> Self {
+            value.0.into()
+        }
+    }
+
+    #[test]
+    fn test_unsorted_factors() {
+        let sorted = BoxProduct::new(c_n_graph(5), c_n_graph(4));
+        let unsorted = BoxProduct::new(Reversed(c_n_graph(5)), Reversed(c_n_graph(4)));
+        for v in 0..sorted.n() {
+            let mut neighbors = unsorted.neighbors(v).collect::<Vec<_>>();
+            neighbors.sort_unstable();
+            assert!(neighbors.into_iter().eq(sorted.neighbors(v)));
+        }
+    }
+
+    #[test]
+    fn test_projections() {
+        let torus = BoxProduct::new(c_n_graph(5), c_n_graph(4));
+        assert_reflexive_symmetric(&torus);
+        let (left, right) = (torus.left_projection(), torus.right_projection());
+        for v in 0..torus.n() {
+            assert_eq!(torus.pair(left.map(v), right.map(v)), v);
+        }
+        let mut workspace = vec![0; torus.n() as usize];
+        assert!(VertGraphMap::try_from(
+            Cow::Borrowed(&torus),
+            Cow::Borrowed(torus.left()),
+            left.mapped_vertices(),
+            &mut workspace
+        )
+        .is_ok());
+        assert!(VertGraphMap::try_from(
+            Cow::Borrowed(&torus),
+            Cow::Borrowed(torus.right()),
+            right.mapped_vertices(),
+            &mut workspace
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_torus_kunneth() {
+        // H(C_5) = [1, 1, 0], so the discrete torus C_5 □ C_5 has H = [1, 2, 1]
+        let torus = BoxProduct::new(c_n_graph(5), c_n_graph(5));
+        assert_eq!(
+            betti_numbers(&torus, 2, CubeComplex::Normalized),
+            vec![1, 2, 1]
+        );
+    }
+
+    #[test]
+    fn test_strong_product() {
+        let product = StrongProduct::new(c_n_graph(5), c_n_graph(4));
+        assert_reflexive_symmetric(&product);
+        for v in 0..product.n() {
+            assert_eq!(product.degree(v), 9);
+        }
+
+        // The strong product of paths I_1 is the complete graph K_4
+        let k2 = CubeGraph::<u32>::new(1);
+        let k4: TensorProduct<_, _> = StrongProduct::new(k2, k2);
+        assert!((0..4u32).all(|v| (0..4u32).all(|w| k4.is_edge(v, w))));
+    }
+
+    #[test]
+    fn test_pair_maps() {
+        let c5 = c_n_graph(5);
+        let c4 = c_n_graph(4);
+        let product = StrongProduct::new(c5.clone(), c4.clone());
+        let rotation = VertGraphMap::try_from(
+            Cow::Borrowed(&c5),
+            Cow::Borrowed(&c5),
+            [1, 2, 3, 4, 0],
+            &mut [0; 5],
+        )
+        .unwrap();
+        let fold = VertGraphMap::try_from(
+            Cow::Borrowed(&c5),
+            Cow::Borrowed(&c4),
+            [0, 1, 2, 1, 0],
+            &mut [0; 5],
+        )
+        .unwrap();
+        let paired = product.pair_maps(&rotation, &fold);
+        assert!(VertGraphMap::try_from(
+            Cow::Borrowed(&c5),
+            Cow::Borrowed(&product),
+            paired.mapped_vertices(),
+            &mut [0; 5]
+        )
+        .is_ok());
+
+        // Composing with the projections recovers the factors
+        let (left, right) = (product.left_projection(), product.right_projection());
+        assert!(compose(&paired, &left)
+            .mapped_vertices()
+            .eq(rotation.mapped_vertices()));
+        assert!(compose(&paired, &right)
+            .mapped_vertices()
+            .eq(fold.mapped_vertices()));
+    }
+}
@@ -0,0 +1,119 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::graph_maps::VertGraphMap;
+use crate::prelude::*;
+
+/// The quotient of `graph` identifying vertices with equal `labels`, where two blocks are
+/// adjacent when any of their members are. Blocks are numbered in order of their smallest
+/// vertex, and returned with the projection sending each vertex to its block.
+pub fn quotient<'g, G: UGraph>(
+    graph: &'g G,
+    labels: &[u32],
+) -> (CSRGraph, VertGraphMap<'g, 'static, G, CSRGraph>) {
+    assert!(
+        labels.len() == graph.n() as usize,
+        "expected {} labels, got {}",
+        graph.n(),
+        labels.len()
+    );
+    let mut blocks = HashMap::new();
+    let dense = labels
+        .iter()
+        .map(|&label| {
+            let next = blocks.len() as u32;
+            *blocks.entry(label).or_insert(next)
+        })
+        .collect::<Vec<_>>();
+    quotient_dense(graph, dense, blocks.len())
+}
+
+/// Like [quotient], with the blocks of a partition of the vertices given explicitly. Block `i`
+/// of the quotient is `partition[i]`.
+pub fn quotient_by_partition<'g, G: UGraph>(
+    graph: &'g G,
+    partition: &[Vec<u32>],
+) -> (CSRGraph, VertGraphMap<'g, 'static, G, CSRGraph>) {
+    let mut labels = vec![u32::MAX; graph.n() as usize];
+    for (i, block) in partition.iter().enumerate() {
+        assert!(!block.is_empty(), "block {i} of the partition is empty");
+        for &v in block {
+            assert!(
+                labels[v as usize] == u32::MAX,
+                "vertex {v} is in more than one block"
+            );
+            labels[v as usize] = i as u32;
+        }
+    }
+    if let Some(v) = labels.iter().position(|&label| label == u32::MAX) {
+        panic!("vertex {v} is in no block");
+    }
+    quotient_dense(graph, labels, partition.len())
+}
+
+fn quotient_dense<G: UGraph>(
+    graph: &G,
+    labels: Vec<u32>,
+    num_blocks: usize,
+) -> (CSRGraph, VertGraphMap<'_, 'static, G, CSRGraph>) {
+    let mut adjacent = vec![Vec::new(); num_blocks];
+    for v in 0..graph.n() {
+        let block = labels[v as usize];
+        adjacent[block as usize].extend(graph.neighbors(v).map(|w| labels[w as usize]));
+    }
+    let quotient = CSRGraph::from_neighbor_lists(adjacent);
+
+    let mut workspace = vec![0; graph.n() as usize];
+    let projection = VertGraphMap::try_from(
+        Cow::Borrowed(graph),
+        Cow::Owned(quotient.clone()),
+        labels,
+        &mut workspace,
+    )
+    .expect("every edge lies over an edge of the quotient");
+    (quotient, projection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::GraphMap;
+    use crate::graphs::extras::c_n_graph;
+    use crate::homology::{betti_numbers, CubeComplex};
+
+    #[test]
+    fn test_collapse_edge() {
+        // Collapsing an edge of C_6 gives C_5
+        let c6 = c_n_graph(6);
+        let (collapsed, projection) = quotient(&c6, &[0, 0, 1, 2, 3, 4]);
+        assert_eq!(collapsed, c_n_graph(5));
+        assert!(projection.mapped_vertices().eq([0, 0, 1, 2, 3, 4]));
+
+        // Labels need not be dense, blocks are ordered by their smallest vertex
+        let (relabelled, _) = quotient(&c6, &[7, 7, 3, 9, 1, 5]);
+        assert_eq!(relabelled, collapsed);
+    }
+
+    #[test]
+    fn test_collapse_contractible_subgraph() {
+        // Collapsing a path of three vertices in C_8 gives C_6, with the same homology
+        let c8 = c_n_graph(8);
+        let partition = [vec![0, 1, 2], vec![3], vec![4], vec![5], vec![6], vec![7]];
+        let (collapsed, _) = quotient_by_partition(&c8, &partition);
+        assert_eq!(collapsed, c_n_graph(6));
+        assert_eq!(
+            betti_numbers(&collapsed, 1, CubeComplex::Normalized),
+            betti_numbers(&c8, 1, CubeComplex::Normalized)
+        );
+
+        let (point, _) = quotient(&c8, &[0; 8]);
+        assert_eq!(point, CSRGraph::new(vec![0, 1], vec![0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "vertex 2 is in more than one block")]
+    fn test_overlapping_partition() {
+        let c5 = c_n_graph(5);
+        quotient_by_partition(&c5, &[vec![0, 1, 2], vec![2, 3, 4]]);
+    }
+}
@@ -0,0 +1,166 @@
+//! Graph models of discrete spheres.
+//!
+//! The cross-polytope (octahedral) graphs are not discrete spheres: all their 3- and 4-cycles
+//! are filled, and for dimensions up to 3 their homology through `H_2` is that of a point.
+//! Subdividing them into cubes gives back [cube_boundary_sphere] at scale 2, relabelled, so
+//! there is no separate octahedral model here.
+
+use crate::graphs::extras::c_n_graph;
+use crate::graphs::families::GridGraph;
+use crate::graphs::subgraph::InducedSubgraph;
+use crate::prelude::*;
+
+/// The generalized Greene sphere of dimension `dim >= 1`.
+///
+/// The 1-sphere is `C_8`. The `(k + 1)`-sphere is the bipartite `k`-sphere, shifted up by one,
+/// with a new vertex 0 adjacent to one side of the bipartition and a new last vertex adjacent
+/// to the other, so the result is bipartite again with `2 dim + 6` vertices. Dimension 2 is
+/// [greene_sphere](crate::graphs::extras::greene_sphere).
+///
+/// Expected normalized homology is that of `S^dim`. This is checked for dimensions 1 and 2.
+/// From dimension 3 on the sphere is conjectural: `H_0..=H_2` are checked and match a point,
+/// but the top degree needs all 4- and 5-cube maps, and the ignored test computing it runs
+/// out of memory with the current cube enumeration.
+pub fn greene_sphere_n(dim: u32) -> CSRGraph {
+    assert!(dim >= 1, "spheres start in dimension 1");
+    let mut sphere = c_n_graph(8);
+    for _ in 1..dim {
+        sphere = add_poles(&sphere);
+    }
+    sphere
+}
+
+fn add_poles(sphere: &CSRGraph) -> CSRGraph {
+    let n = sphere.n();
+    // Vertex 0 of the previous sphere has colour 0
+    let mut colour = vec![u32::MAX; n as usize];
+    colour[0] = 0;
+    let mut queue = vec![0];
+    while let Some(v) = queue.pop() {
+        for w in sphere.neighbors(v) {
+            if colour[w as usize] == u32::MAX {
+                colour[w as usize] = 1 - colour[v as usize];
+                queue.push(w);
+            }
+        }
+    }
+
+    let (north, south) = (0, n + 1);
+    let mut adjacent = vec![Vec::new(); (n + 2) as usize];
+    adjacent[north as usize].push(north);
+    adjacent[south as usize].push(south);
+    for v in 0..n {
+        debug_assert!(sphere
+            .neighbors(v)
+            .all(|w| w == v || colour[w as usize] != colour[v as usize]));
+        adjacent[(v + 1) as usize].extend(sphere.neighbors(v).map(|w| w + 1));
+        let pole = if colour[v as usize] == 1 {
+            north
+        } else {
+            south
+        };
+        adjacent[(v + 1) as usize].push(pole);
+        adjacent[pole as usize].push(v + 1);
+    }
+    CSRGraph::from_neighbor_lists(adjacent)
+}
+
+/// The boundary of the grid `I_scale^(dim + 1)`, the vertices with some coordinate equal to 0
+/// or `scale`, relabelled in increasing order of their grid encoding.
+///
+/// Expected normalized homology is that of `S^dim`, checked for dimensions 1 and 2 at scales 2
+/// and 3. From dimension 3 on the sphere is conjectural, for the same reason as in
+/// [greene_sphere_n]. At scale 2 in dimension 1 this is a relabelled `C_8`.
+pub fn cube_boundary_sphere(dim: u32, scale: u32) -> CSRGraph {
+    assert!(scale >= 2, "the boundary of I_1^n is the whole cube");
+    let grid = GridGraph::uniform(scale, dim + 1);
+    let boundary = (0..grid.n()).filter(|&v| grid.is_boundary(v));
+    CSRGraph::from_graph(&InducedSubgraph::new(&grid, boundary))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::extras::greene_sphere;
+    use crate::homology::{betti_numbers, CubeComplex};
+
+    #[test]
+    fn test_greene_spheres() {
+        assert_eq!(greene_sphere_n(1), c_n_graph(8));
+        assert_eq!(greene_sphere_n(2), greene_sphere());
+        for dim in 1..=4 {
+            let sphere = greene_sphere_n(dim);
+            assert_eq!(sphere.n(), 2 * dim + 6);
+            assert_eq!(assert_reflexive_symmetric(&sphere), sphere);
+        }
+        assert_eq!(
+            betti_numbers(&greene_sphere_n(1), 1, CubeComplex::Normalized),
+            vec![1, 1]
+        );
+    }
+
+    #[test]
+    fn test_greene_spheres_low_degrees() {
+        // Only H_0..=H_2, the top degrees of dimensions 3 and 4 are unverified
+        for dim in 3..=4 {
+            assert_eq!(
+                betti_numbers(&greene_sphere_n(dim), 2, CubeComplex::Normalized),
+                vec![1, 0, 0]
+            );
+        }
+    }
+
+    #[test]
+    #[ignore = "needs all 4- and 5-cube maps; runs out of memory in a few GB"]
+    fn test_greene_spheres_top_degree() {
+        assert_eq!(
+            betti_numbers(&greene_sphere_n(3), 3, CubeComplex::Normalized),
+            vec![1, 0, 0, 1]
+        );
+        assert_eq!(
+            betti_numbers(&greene_sphere_n(4), 4, CubeComplex::Normalized),
+            vec![1, 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_cube_boundary_spheres() {
+        // The square of side 2, a relabelled C_8
+        let octagon = cube_boundary_sphere(1, 2);
+        assert_eq!(octagon.n(), 8);
+        assert!((0..8).all(|v| octagon.degree(v) == 3));
+        assert_eq!(
+            betti_numbers(&octagon, 1, CubeComplex::Normalized),
+            vec![1, 1]
+        );
+        let circle = cube_boundary_sphere(1, 3);
+        assert_eq!(circle.n(), 12);
+        assert_eq!(
+            betti_numbers(&circle, 1, CubeComplex::Normalized),
+            vec![1, 1]
+        );
+        let sphere = cube_boundary_sphere(2, 2);
+        assert_eq!(sphere.n(), 26);
+        assert_eq!(
+            betti_numbers(&sphere, 2, CubeComplex::Normalized),
+            vec![1, 0, 1]
+        );
+        let sphere = cube_boundary_sphere(2, 3);
+        assert_eq!(sphere.n(), 56);
+        assert_eq!(
+            betti_numbers(&sphere, 2, CubeComplex::Normalized),
+            vec![1, 0, 1]
+        );
+    }
+
+    #[test]
+    #[ignore = "needs all 4-cube maps into 80 vertices; runs out of memory in a few GB"]
+    fn test_cube_boundary_sphere_top_degree() {
+        let sphere = cube_boundary_sphere(3, 2);
+        assert_eq!(sphere.n(), 80);
+        assert_eq!(
+            betti_numbers(&sphere, 3, CubeComplex::Normalized),
+            vec![1, 0, 0, 1]
+        );
+    }
+}
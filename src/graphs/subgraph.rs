@@ -0,0 +1,130 @@
+use std::borrow::Cow;
+
+use crate::graph_maps::VertGraphMap;
+use crate::prelude::*;
+
+/// The subgraph of `graph` induced on a subset of its vertices, relabelled `0..k` in
+/// increasing order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InducedSubgraph<'g, G: UGraph> {
+    graph: &'g G,
+    /// The vertices of `graph` in the subgraph, sorted
+    vertices: Vec<u32>,
+    /// The label of each vertex of `graph` in the subgraph, `u32::MAX` if absent
+    labels: Vec<u32>,
+}
+
+impl<'g, G: UGraph> InducedSubgraph<'g, G> {
+    pub fn new(graph: &'g G, vertices: impl IntoIterator<Item = u32>) -> Self {
+        let mut vertices = vertices.into_iter().collect::<Vec<_>>();
+        vertices.sort_unstable();
+        vertices.dedup();
+        let mut labels = vec![u32::MAX; graph.n() as usize];
+        for (i, &v) in vertices.iter().enumerate() {
+            assert!(v < graph.n(), "vertex {v} out of range {}", graph.n());
+            labels[v as usize] = i as u32;
+        }
+        Self {
+            graph,
+            vertices,
+            labels,
+        }
+    }
+
+    pub fn parent(&self) -> &'g G {
+        self.graph
+    }
+
+    /// The vertices of the parent graph in the subgraph, in label order
+    pub fn vertices(&self) -> &[u32] {
+        &self.vertices
+    }
+
+    /// The label of vertex `v` of the parent graph, if it is in the subgraph
+    pub fn label_of(&self, v: u32) -> Option<u32> {
+        match self.labels[v as usize] {
+            u32::MAX => None,
+            label => Some(label),
+        }
+    }
+
+    /// The inclusion into the parent graph
+    pub fn inclusion(&self) -> VertGraphMap<'_, 'g, Self, G> {
+        // Safety: the subgraph has exactly the edges of the parent between its vertices
+        unsafe {
+            VertGraphMap::new_unchecked(
+                Cow::Borrowed(self),
+                Cow::Borrowed(self.graph),
+                Cow::Borrowed(&self.vertices),
+            )
+        }
+    }
+}
+
+impl<G: UGraph> GraphNeighbors for InducedSubgraph<'_, G> {
+    fn neighbors(&self, v: u32) -> impl Iterator<Item = u32> {
+        // Labels increase with the parent's vertices, so this stays sorted
+        self.graph
+            .neighbors(self.vertices[v as usize])
+            .filter_map(|w| self.label_of(w))
+    }
+}
+
+impl<G: UGraph> UGraph for InducedSubgraph<'_, G> {
+    fn n(&self) -> u32 {
+        self.vertices.len() as u32
+    }
+
+    fn degree(&self, v: u32) -> u32 {
+        self.neighbors(v).count() as u32
+    }
+
+    fn is_edge<V: Into<u32>>(&self, a: V, b: V) -> bool {
+        let (a, b) = (a.into(), b.into());
+        self.graph
+            .is_edge(self.vertices[a as usize], self.vertices[b as usize])
+    }
+}
+
+impl<G: UGraph> From<InducedSubgraph<'_, G>> for AdjMatrix {
+    fn from(value: InducedSubgraph<'_, G>) -> Self {
+        adjacency_matrix(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::GraphMap;
+    use crate::graphs::extras::{c_n_graph, greene_sphere, grid_graph};
+    use crate::graphs::folds::fold_dominated;
+
+    #[test]
+    fn test_induced_subgraph() {
+        let greene = greene_sphere();
+        let link = InducedSubgraph::new(&greene, greene.neighbors(0).filter(|&v| v != 0));
+        assert_eq!(link.vertices(), &[2, 4, 6, 8]);
+        assert_reflexive_symmetric(&link);
+        assert_eq!(link.label_of(4), Some(1));
+        assert_eq!(link.label_of(3), None);
+
+        let inclusion = link.inclusion();
+        let mut workspace = vec![0; 4];
+        assert!(VertGraphMap::try_from(
+            Cow::Borrowed(&link),
+            Cow::Borrowed(&greene),
+            inclusion.mapped_vertices(),
+            &mut workspace
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_punctured_cycle_is_path() {
+        let c6 = c_n_graph(6);
+        let punctured = InducedSubgraph::new(&c6, [1, 2, 3, 4, 5]);
+        let path = assert_reflexive_symmetric(&punctured);
+        assert_eq!(path, grid_graph(4, 1));
+        assert_eq!(fold_dominated(&punctured).0.n(), 1);
+    }
+}
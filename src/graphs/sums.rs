@@ -0,0 +1,136 @@
+use std::borrow::Cow;
+
+use crate::graph_maps::VertGraphMap;
+use crate::prelude::*;
+
+/// The disjoint union of `graphs`, with the vertices of each graph following those of the
+/// previous ones, and the inclusion of each graph.
+pub fn disjoint_union<G: UGraph>(
+    graphs: &[G],
+) -> (CSRGraph, Vec<VertGraphMap<'_, 'static, G, CSRGraph>>) {
+    let mut start = 0;
+    let images = graphs
+        .iter()
+        .map(|graph| {
+            let images = (start..start + graph.n()).collect::<Vec<_>>();
+            start += graph.n();
+            images
+        })
+        .collect::<Vec<_>>();
+    let mut adjacent = vec![Vec::new(); start as usize];
+    for (graph, images) in graphs.iter().zip(&images) {
+        add_edges(&mut adjacent, graph, images);
+    }
+    let union = CSRGraph::from_neighbor_lists(adjacent);
+    let inclusions = graphs
+        .iter()
+        .zip(images)
+        .map(|(graph, images)| include(graph, &union, images))
+        .collect();
+    (union, inclusions)
+}
+
+/// The wedge sum `G ∨ H` identifying `g0` with `h0`, with the inclusions of both graphs.
+///
+/// The vertices of `G` keep their labels and the remaining vertices of `H` follow in order.
+#[allow(clippy::type_complexity)]
+pub fn wedge<'g, 'h, G: UGraph, H: UGraph>(
+    left: &'g G,
+    g0: u32,
+    right: &'h H,
+    h0: u32,
+) -> (
+    CSRGraph,
+    VertGraphMap<'g, 'static, G, CSRGraph>,
+    VertGraphMap<'h, 'static, H, CSRGraph>,
+) {
+    assert!(g0 < left.n() && h0 < right.n());
+    let n = left.n();
+    let left_images = (0..n).collect::<Vec<_>>();
+    let right_images = (0..right.n())
+        .map(|v| match v.cmp(&h0) {
+            std::cmp::Ordering::Less => n + v,
+            std::cmp::Ordering::Equal => g0,
+            std::cmp::Ordering::Greater => n + v - 1,
+        })
+        .collect::<Vec<_>>();
+    let mut adjacent = vec![Vec::new(); (n + right.n() - 1) as usize];
+    add_edges(&mut adjacent, left, &left_images);
+    add_edges(&mut adjacent, right, &right_images);
+    let sum = CSRGraph::from_neighbor_lists(adjacent);
+    let left_inclusion = include(left, &sum, left_images);
+    let right_inclusion = include(right, &sum, right_images);
+    (sum, left_inclusion, right_inclusion)
+}
+
+/// Adds the edges of `graph` to `adjacent`, with vertex `v` relabelled `images[v]`.
+fn add_edges<G: UGraph>(adjacent: &mut [Vec<u32>], graph: &G, images: &[u32]) {
+    for v in 0..graph.n() {
+        let image = images[v as usize];
+        adjacent[image as usize].extend(graph.neighbors(v).map(|w| images[w as usize]));
+    }
+}
+
+fn include<'g, G: UGraph>(
+    graph: &'g G,
+    sum: &CSRGraph,
+    images: Vec<u32>,
+) -> VertGraphMap<'g, 'static, G, CSRGraph> {
+    let mut workspace = vec![0; graph.n() as usize];
+    VertGraphMap::try_from(
+        Cow::Borrowed(graph),
+        Cow::Owned(sum.clone()),
+        images,
+        &mut workspace,
+    )
+    .expect("every edge of a summand is an edge of the sum")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::GraphMap;
+    use crate::graphs::connected_components;
+    use crate::graphs::extras::{c_n_graph, greene_sphere};
+    use crate::homology::{betti_numbers, CubeComplex};
+
+    #[test]
+    fn test_disjoint_union() {
+        let cycles = [c_n_graph(4), c_n_graph(5), c_n_graph(6)];
+        let (union, inclusions) = disjoint_union(&cycles);
+        assert_eq!(union.n(), 15);
+        assert_eq!(connected_components(&union).len(), 3);
+        assert!(inclusions[1].mapped_vertices().eq(4..9));
+        assert_eq!(assert_reflexive_symmetric(&union), union);
+        assert_eq!(
+            betti_numbers(&union, 1, CubeComplex::Normalized),
+            vec![3, 2]
+        );
+    }
+
+    #[test]
+    fn test_wedge_of_cycles() {
+        let c5 = c_n_graph(5);
+        let c6 = c_n_graph(6);
+        let (sum, left, right) = wedge(&c5, 2, &c6, 3);
+        assert_eq!(sum.n(), 10);
+        assert!(left.mapped_vertices().eq(0..5));
+        assert!(right.mapped_vertices().eq([5, 6, 7, 2, 8, 9]));
+        assert_eq!(sum.degree(2), 5);
+        assert_eq!(
+            betti_numbers(&sum, 2, CubeComplex::Normalized),
+            vec![1, 2, 0]
+        );
+    }
+
+    #[test]
+    fn test_wedge_homology_is_additive() {
+        let greene = greene_sphere();
+        let c5 = c_n_graph(5);
+        let (sum, _, _) = wedge(&greene, 0, &c5, 0);
+        assert_eq!(
+            betti_numbers(&sum, 2, CubeComplex::Normalized),
+            vec![1, 1, 1]
+        );
+    }
+}
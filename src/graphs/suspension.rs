@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+
+use crate::graph_maps::{GraphMap, VertGraphMap};
+use crate::graphs::extras::grid_graph;
+use crate::graphs::product::BoxProduct;
+use crate::graphs::quotient::quotient;
+use crate::prelude::*;
+
+/// The cone on `graph`: the box product `G □ I_length` with `G × {length}` collapsed to a
+/// single apex, and the inclusion of `G` as `G × {0}`.
+pub fn cone<G: UGraph>(
+    graph: &G,
+    length: u32,
+) -> (CSRGraph, VertGraphMap<'_, 'static, G, CSRGraph>) {
+    assert!(length >= 1, "the cone needs a path of at least one step");
+    collapse_levels(graph, length, &[length], 0)
+}
+
+/// The suspension of `graph`: the box product `G □ I_length` with `G × {0}` and
+/// `G × {length}` each collapsed to a pole, and the inclusion of `G` as `G × {length / 2}`.
+pub fn suspension<G: UGraph>(
+    graph: &G,
+    length: u32,
+) -> (CSRGraph, VertGraphMap<'_, 'static, G, CSRGraph>) {
+    assert!(
+        length >= 2,
+        "the suspension needs a level strictly between the poles"
+    );
+    collapse_levels(graph, length, &[0, length], length / 2)
+}
+
+/// `G □ I_length` with each of the `collapsed` levels collapsed to a vertex, and the
+/// inclusion of `G` at `level`.
+fn collapse_levels<'g, G: UGraph>(
+    graph: &'g G,
+    length: u32,
+    collapsed: &[u32],
+    level: u32,
+) -> (CSRGraph, VertGraphMap<'g, 'static, G, CSRGraph>) {
+    let product = BoxProduct::new(graph.clone(), grid_graph(length, 1));
+    let labels = (0..product.n())
+        .map(|v| {
+            let (g, t) = product.split(v);
+            // Collapsed levels get labels past the vertices of the product
+            match collapsed.iter().position(|&c| c == t) {
+                Some(i) => product.n() + i as u32,
+                None => product.pair(g, t),
+            }
+        })
+        .collect::<Vec<_>>();
+    let (collapsed, projection) = quotient(&product, &labels);
+
+    let images = (0..graph.n()).map(|g| projection.map(product.pair(g, level)));
+    let mut workspace = vec![0; graph.n() as usize];
+    let inclusion = VertGraphMap::try_from(
+        Cow::Borrowed(graph),
+        Cow::Owned(collapsed.clone()),
+        images,
+        &mut workspace,
+    )
+    .expect("a level of the product is a copy of the graph");
+    (collapsed, inclusion)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::extras::{c_n_graph, greene_sphere};
+    use crate::homology::{betti_numbers, CubeComplex};
+
+    #[test]
+    fn test_cone() {
+        let c5 = c_n_graph(5);
+        let (cone, inclusion) = cone(&c5, 2);
+        assert_eq!(cone.n(), 11);
+        // Blocks are numbered in order of their smallest vertex (g, t) = 3g + t, and the
+        // apex is the block of (0, 2)
+        assert!(inclusion.mapped_vertices().eq([0, 3, 5, 7, 9]));
+        assert_eq!(
+            betti_numbers(&cone, 2, CubeComplex::Normalized),
+            vec![1, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_suspension() {
+        // With the poles two steps apart the suspension is contractible
+        let c5 = c_n_graph(5);
+        let (squashed, _) = suspension(&c5, 2);
+        assert_eq!(
+            betti_numbers(&squashed, 2, CubeComplex::Normalized),
+            vec![1, 0, 0]
+        );
+
+        // From three steps on it has the homology of the Greene sphere
+        let (sphere, inclusion) = suspension(&c5, 3);
+        assert_eq!(sphere.n(), 12);
+        assert!(inclusion.mapped_vertices().all(|v| v != 0));
+        assert_eq!(
+            betti_numbers(&sphere, 2, CubeComplex::Normalized),
+            betti_numbers(&greene_sphere(), 2, CubeComplex::Normalized)
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+/// The prime `2^31 - 1`, used as the coefficient field for ranks.
+pub const PRIME: u64 = 2_147_483_647;
+
+fn pow_mod(mut base: u64, mut exp: u64) -> u64 {
+    let mut acc = 1;
+    base %= PRIME;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = acc * base % PRIME;
+        }
+        base = base * base % PRIME;
+        exp >>= 1;
+    }
+    acc
+}
+
+fn inverse_mod(x: u64) -> u64 {
+    debug_assert!(!x.is_multiple_of(PRIME));
+    pow_mod(x, PRIME - 2)
+}
+
+// Sorts by column, sums duplicate columns and drops zeros
+fn normalize(row: Vec<(usize, i64)>) -> Vec<(usize, u64)> {
+    let mut row = row
+        .into_iter()
+        .map(|(col, val)| (col, val.rem_euclid(PRIME as i64) as u64))
+        .collect::<Vec<_>>();
+    row.sort_unstable_by_key(|(col, _)| *col);
+    let mut out: Vec<(usize, u64)> = Vec::with_capacity(row.len());
+    for (col, val) in row {
+        match out.last_mut() {
+            Some((last, acc)) if *last == col => *acc = (*acc + val) % PRIME,
+            _ => out.push((col, val)),
+        }
+    }
+    out.retain(|(_, val)| *val != 0);
+    out
+}
+
+// row + coef * pivot, both sorted by column
+fn add_multiple(row: &[(usize, u64)], pivot: &[(usize, u64)], coef: u64) -> Vec<(usize, u64)> {
+    let mut out = Vec::with_capacity(row.len() + pivot.len());
+    let (mut i, mut j) = (0, 0);
+    while i < row.len() || j < pivot.len() {
+        let take_row = j == pivot.len() || (i < row.len() && row[i].0 < pivot[j].0);
+        let take_pivot = i == row.len() || (j < pivot.len() && pivot[j].0 < row[i].0);
+        if take_row {
+            out.push(row[i]);
+            i += 1;
+        } else if take_pivot {
+            out.push((pivot[j].0, pivot[j].1 * coef % PRIME));
+            j += 1;
+        } else {
+            let val = (row[i].1 + pivot[j].1 * coef) % PRIME;
+            if val != 0 {
+                out.push((row[i].0, val));
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+    out
+}
+
+/// Rank over `F_p` ([PRIME]) of the matrix whose rows are given as sparse `(column, value)` lists.
+pub fn rank_mod_p(rows: impl IntoIterator<Item = Vec<(usize, i64)>>) -> usize {
+    // Pivot rows keyed by their leading column, scaled so the leading entry is 1
+    let mut pivots: HashMap<usize, Vec<(usize, u64)>> = HashMap::new();
+    for row in rows {
+        let mut row = normalize(row);
+        while let Some(&(lead, val)) = row.first() {
+            match pivots.get(&lead) {
+                Some(pivot) => row = add_multiple(&row, pivot, PRIME - val),
+                None => {
+                    let inv = inverse_mod(val);
+                    row.iter_mut().for_each(|(_, v)| *v = *v * inv % PRIME);
+                    pivots.insert(lead, row);
+                    break;
+                }
+            }
+        }
+    }
+    pivots.len()
+}
+
+/// The non-zero diagonal entries of the Smith normal form of an integer matrix, i.e. its
+/// invariant factors `d_1 | d_2 | ...`, all positive.
+#[allow(clippy::needless_range_loop)]
+pub fn invariant_factors(mut matrix: Vec<Vec<i64>>) -> Vec<i64> {
+    let rows = matrix.len();
+    let cols = matrix.first().map_or(0, |row| row.len());
+    debug_assert!(matrix.iter().all(|row| row.len() == cols));
+
+    let mut factors = Vec::new();
+    for t in 0..rows.min(cols) {
+        // Smallest non-zero entry of the remaining submatrix as pivot
+        let pivot = (t..rows)
+            .flat_map(|i| (t..cols).map(move |j| (i, j)))
+            .filter(|&(i, j)| matrix[i][j] != 0)
+            .min_by_key(|&(i, j)| matrix[i][j].abs());
+        let Some((pi, pj)) = pivot else {
+            break;
+        };
+        matrix.swap(t, pi);
+        matrix.iter_mut().for_each(|row| row.swap(t, pj));
+
+        loop {
+            let p = matrix[t][t];
+            let mut done = true;
+            for i in t + 1..rows {
+                let q = matrix[i][t] / p;
+                if q != 0 {
+                    for j in t..cols {
+                        matrix[i][j] -= q * matrix[t][j];
+                    }
+                }
+                done &= matrix[i][t] == 0;
+            }
+            for j in t + 1..cols {
+                let q = matrix[t][j] / p;
+                if q != 0 {
+                    for row in matrix.iter_mut() {
+                        row[j] -= q * row[t];
+                    }
+                }
+                done &= matrix[t][j] == 0;
+            }
+            // The pivot must also divide the rest of the submatrix
+            if done {
+                let bad_row = (t + 1..rows).find(|&i| (t + 1..cols).any(|j| matrix[i][j] % p != 0));
+                match bad_row {
+                    Some(i) => {
+                        for j in t..cols {
+                            matrix[t][j] += matrix[i][j];
+                        }
+                    }
+                    None => break,
+                }
+            }
+            // Move the smallest remaining entry of row and column t to the pivot
+            let (i, j) = (t..rows)
+                .map(|i| (i, t))
+                .chain((t..cols).map(|j| (t, j)))
+                .filter(|&(i, j)| matrix[i][j] != 0)
+                .min_by_key(|&(i, j)| matrix[i][j].abs())
+                .unwrap();
+            matrix.swap(t, i);
+            matrix.iter_mut().for_each(|row| row.swap(t, j));
+        }
+        factors.push(matrix[t][t].abs());
+    }
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_mod_p() {
+        assert_eq!(rank_mod_p(Vec::<Vec<(usize, i64)>>::new()), 0);
+        assert_eq!(rank_mod_p(vec![vec![(0, 0)], vec![(3, PRIME as i64)]]), 0);
+
+        // Identity plus a dependent row
+        let rows = vec![
+            vec![(0, 1), (1, 0), (2, 0)],
+            vec![(1, 1)],
+            vec![(2, 1)],
+            vec![(0, 2), (1, -3), (2, 5)],
+        ];
+        assert_eq!(rank_mod_p(rows), 3);
+
+        // Boundary of a triangle: e01 = v1 - v0, e12 = v2 - v1, e02 = v2 - v0
+        let rows = vec![
+            vec![(1, 1), (0, -1)],
+            vec![(2, 1), (1, -1)],
+            vec![(2, 1), (0, -1)],
+        ];
+        assert_eq!(rank_mod_p(rows), 2);
+
+        // Duplicate columns are summed before elimination
+        assert_eq!(rank_mod_p(vec![vec![(4, 1), (4, -1)]]), 0);
+    }
+
+    #[test]
+    fn test_invariant_factors() {
+        assert_eq!(invariant_factors(vec![]), Vec::<i64>::new());
+        assert_eq!(
+            invariant_factors(vec![vec![0, 0], vec![0, 0]]),
+            Vec::<i64>::new()
+        );
+        assert_eq!(invariant_factors(vec![vec![2, 0], vec![0, 3]]), vec![1, 6]);
+        assert_eq!(invariant_factors(vec![vec![-4]]), vec![4]);
+        assert_eq!(
+            invariant_factors(vec![vec![2, 4, 4], vec![-6, 6, 12], vec![10, -4, -16]]),
+            vec![2, 6, 12]
+        );
+        // Z^3 / <(1, 1, 0), (0, 2, 2)> is Z + Z/2
+        assert_eq!(
+            invariant_factors(vec![vec![1, 1, 0], vec![0, 2, 2]]),
+            vec![1, 2]
+        );
+    }
+}
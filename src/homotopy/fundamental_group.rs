@@ -0,0 +1,320 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::homology::linalg::invariant_factors;
+use crate::prelude::*;
+
+/// A finite presentation of a group.
+///
+/// Letter `i + 1` of a relator is generator `i`, and `-(i + 1)` its inverse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupPresentation {
+    /// The edge `(a, b)` of the graph, traversed from `a` to `b`, that each generator stands for
+    pub generators: Vec<(u32, u32)>,
+    pub relations: Vec<Vec<i32>>,
+}
+
+/// A finitely generated abelian group `Z^rank + Z/t_1 + ... + Z/t_k`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Abelianization {
+    pub rank: usize,
+    /// The torsion coefficients `t_i > 1`, each dividing the next
+    pub torsion: Vec<u64>,
+}
+
+impl GroupPresentation {
+    pub fn is_trivial(&self) -> bool {
+        self.generators.is_empty()
+    }
+
+    /// The abelianization, from the Smith normal form of the exponent sums of the relators.
+    pub fn abelianization(&self) -> Abelianization {
+        let n = self.generators.len();
+        let matrix = self
+            .relations
+            .iter()
+            .map(|word| {
+                let mut row = vec![0i64; n];
+                for &letter in word {
+                    row[letter.unsigned_abs() as usize - 1] += letter.signum() as i64;
+                }
+                row
+            })
+            .collect::<Vec<_>>();
+        let factors = invariant_factors(matrix);
+        Abelianization {
+            rank: n - factors.len(),
+            torsion: factors
+                .into_iter()
+                .filter(|&t| t > 1)
+                .map(|t| t as u64)
+                .collect(),
+        }
+    }
+}
+
+/// A finite presentation of the discrete fundamental group `A_1(graph, basepoint)`.
+///
+/// This is the fundamental group of the 2-complex with a cell on every 3- and 4-cycle of the
+/// graph. Generators are the edges outside a breadth-first spanning tree of the component of
+/// the basepoint, relators are the boundaries of the cells, and the presentation is then
+/// simplified by Tietze moves eliminating generators that occur once in some relator.
+pub fn fundamental_group<G: UGraph>(graph: &G, basepoint: u32) -> GroupPresentation {
+    assert!(basepoint < graph.n());
+    let n = graph.n() as usize;
+
+    // Breadth-first spanning tree of the component of the basepoint
+    let mut parent: Vec<Option<u32>> = vec![None; n];
+    parent[basepoint as usize] = Some(basepoint);
+    let mut queue = vec![basepoint];
+    let mut head = 0;
+    while head < queue.len() {
+        let v = queue[head];
+        head += 1;
+        for w in graph.neighbors(v) {
+            if parent[w as usize].is_none() {
+                parent[w as usize] = Some(v);
+                queue.push(w);
+            }
+        }
+    }
+    let is_tree_edge =
+        |a: u32, b: u32| parent[a as usize] == Some(b) || parent[b as usize] == Some(a);
+
+    // One generator per non-tree edge (a, b) with a < b
+    let mut generators = Vec::new();
+    let mut edge_index: HashMap<(u32, u32), i32> = HashMap::new();
+    for &a in &queue {
+        for b in graph.neighbors(a) {
+            if a < b && !is_tree_edge(a, b) {
+                generators.push((a, b));
+                edge_index.insert((a, b), generators.len() as i32);
+            }
+        }
+    }
+    let letter = |a: u32, b: u32| -> Option<i32> {
+        if a < b {
+            edge_index.get(&(a, b)).copied()
+        } else {
+            edge_index.get(&(b, a)).map(|&i| -i)
+        }
+    };
+    let cycle_word = |cycle: &[u32]| -> Vec<i32> {
+        (0..cycle.len())
+            .filter_map(|i| letter(cycle[i], cycle[(i + 1) % cycle.len()]))
+            .collect()
+    };
+
+    // Each 3-cycle a < b < c, and each 4-cycle a-b-c-d with a smallest and b < d
+    let mut relations = Vec::new();
+    for &a in &queue {
+        let upper = graph.neighbors(a).filter(|&b| b > a).collect::<Vec<_>>();
+        for (i, &b) in upper.iter().enumerate() {
+            for &d in &upper[i + 1..] {
+                if graph.is_edge(b, d) {
+                    relations.push(cycle_word(&[a, b, d]));
+                }
+                for c in graph.neighbors(b) {
+                    if c > a && c != b && c != d && graph.is_edge(c, d) {
+                        relations.push(cycle_word(&[a, b, c, d]));
+                    }
+                }
+            }
+        }
+    }
+
+    simplify(GroupPresentation {
+        generators,
+        relations,
+    })
+}
+
+fn inverse(word: &[i32]) -> Vec<i32> {
+    word.iter().rev().map(|&x| -x).collect()
+}
+
+// Free and cyclic reduction
+fn reduce(word: Vec<i32>) -> Vec<i32> {
+    let mut out: Vec<i32> = Vec::with_capacity(word.len());
+    for x in word {
+        if out.last() == Some(&-x) {
+            out.pop();
+        } else {
+            out.push(x);
+        }
+    }
+    let mut start = 0;
+    let mut end = out.len();
+    while end - start >= 2 && out[start] == -out[end - 1] {
+        start += 1;
+        end -= 1;
+    }
+    out[start..end].to_vec()
+}
+
+// Smallest rotation of the relator or its inverse, so conjugate relators compare equal
+fn canonical(word: &[i32]) -> Vec<i32> {
+    let inv = inverse(word);
+    (0..word.len())
+        .flat_map(|i| {
+            [
+                [&word[i..], &word[..i]].concat(),
+                [&inv[i..], &inv[..i]].concat(),
+            ]
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn simplify(presentation: GroupPresentation) -> GroupPresentation {
+    let GroupPresentation {
+        generators,
+        relations,
+    } = presentation;
+    let mut relations = relations.into_iter().map(reduce).collect::<Vec<_>>();
+    let mut eliminated = vec![false; generators.len()];
+
+    // Eliminate a generator occurring exactly once in some relator r = x^e w,
+    // substituting x = w^(-e) everywhere else
+    loop {
+        let found = relations.iter().enumerate().find_map(|(r, word)| {
+            let mut counts: HashMap<i32, usize> = HashMap::new();
+            for &x in word {
+                *counts.entry(x.abs()).or_default() += 1;
+            }
+            word.iter()
+                .position(|x| counts[&x.abs()] == 1)
+                .map(|pos| (r, pos))
+        });
+        let Some((r, pos)) = found else {
+            break;
+        };
+        let word = relations.swap_remove(r);
+        let x = word[pos];
+        let rest = [&word[pos + 1..], &word[..pos]].concat();
+        let replacement = if x > 0 { inverse(&rest) } else { rest };
+        let replacement_inv = inverse(&replacement);
+        let generator = x.abs();
+        eliminated[generator as usize - 1] = true;
+        for relation in relations.iter_mut() {
+            let substituted = relation
+                .iter()
+                .flat_map(|&y| {
+                    if y == generator {
+                        replacement.clone()
+                    } else if y == -generator {
+                        replacement_inv.clone()
+                    } else {
+                        vec![y]
+                    }
+                })
+                .collect();
+            *relation = reduce(substituted);
+        }
+    }
+
+    // Renumber the surviving generators and drop trivial or repeated relators
+    let mut renumber = vec![0i32; generators.len()];
+    let mut kept = Vec::new();
+    for (i, generator) in generators.into_iter().enumerate() {
+        if !eliminated[i] {
+            kept.push(generator);
+            renumber[i] = kept.len() as i32;
+        }
+    }
+    let mut seen = HashSet::new();
+    let relations = relations
+        .into_iter()
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            word.iter()
+                .map(|&x| x.signum() * renumber[x.unsigned_abs() as usize - 1])
+                .collect::<Vec<_>>()
+        })
+        .filter(|word| seen.insert(canonical(word)))
+        .collect();
+    GroupPresentation {
+        generators: kept,
+        relations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::cube::{CubeGraph, Newable};
+    use crate::graphs::extras::{c_n_graph, greene_sphere};
+    use crate::homology::{betti_numbers, CubeComplex};
+
+    #[test]
+    fn test_reduce_and_canonical() {
+        assert_eq!(reduce(vec![1, 2, -2, 3]), vec![1, 3]);
+        assert_eq!(reduce(vec![-1, 2, 3, 1]), vec![2, 3]);
+        assert_eq!(reduce(vec![1, -1]), Vec::<i32>::new());
+        assert_eq!(canonical(&[2, 1]), canonical(&[-1, -2]));
+        assert_eq!(canonical(&[3, 1, 2]), vec![-3, -2, -1]);
+    }
+
+    #[test]
+    fn test_cycle_fundamental_group() {
+        for n in 3..5 {
+            assert!(fundamental_group(&c_n_graph(n), 0).is_trivial());
+        }
+        for n in 5..9 {
+            let group = fundamental_group(&c_n_graph(n), 0);
+            assert_eq!(group.generators.len(), 1);
+            assert!(group.relations.is_empty());
+            assert_eq!(
+                group.abelianization(),
+                Abelianization {
+                    rank: 1,
+                    torsion: vec![]
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_simply_connected() {
+        assert!(fundamental_group(&greene_sphere(), 0).is_trivial());
+        assert!(fundamental_group(&greene_sphere(), 9).is_trivial());
+        assert!(fundamental_group(&CubeGraph::new(3), 5).is_trivial());
+    }
+
+    #[test]
+    fn test_abelianization_matches_h1() {
+        let cycle = c_n_graph(6);
+        let sphere = greene_sphere();
+        assert_eq!(
+            fundamental_group(&cycle, 0).abelianization().rank,
+            betti_numbers(&cycle, 1, CubeComplex::Normalized)[1]
+        );
+        assert_eq!(
+            fundamental_group(&sphere, 0).abelianization().rank,
+            betti_numbers(&sphere, 1, CubeComplex::Normalized)[1]
+        );
+    }
+
+    #[test]
+    fn test_basepoint_component() {
+        // A 5-cycle on 0..5 and a 4-cycle on 5..9, disconnected
+        let mut adj: AdjMatrix = vec![vec![false; 9]; 9];
+        for (a, b) in [
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 0),
+            (5, 6),
+            (6, 7),
+            (7, 8),
+            (8, 5),
+        ] {
+            adj[a][b] = true;
+            adj[b][a] = true;
+        }
+        (0..9).for_each(|i| adj[i][i] = true);
+        let graph = CSRGraph::try_from(adj).unwrap();
+        assert_eq!(fundamental_group(&graph, 2).generators.len(), 1);
+        assert!(fundamental_group(&graph, 7).is_trivial());
+    }
+}
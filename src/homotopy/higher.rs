@@ -0,0 +1,195 @@
+//! Experimental brute-force computation of the higher discrete homotopy groups `A_n(G, v0)`.
+//!
+//! An element of `A_n(G, v0)` is represented by a map `I_m^n -> G` sending the boundary of the
+//! grid to `v0`, for some size `m`. Two such maps are identified when they are connected by
+//! one-step homotopies fixing the boundary, and when one is the other padded by `v0` to a
+//! larger grid. [GridMaps] enumerates the maps and classes for a single size, and
+//! [GridMaps::stabilize] relates consecutive sizes.
+//!
+//! Everything here is exponential in `(m - 1)^n` and only meant as a reference for small cases.
+
+use crate::graph_maps::hom_graph::{generate_maps_within, HomGraph, MapSet};
+use crate::graphs::connected_components;
+use crate::graphs::families::GridGraph;
+use crate::prelude::*;
+
+/// All based maps `I_size^dim -> G` sending the boundary of the grid to the basepoint,
+/// grouped into homotopy classes rel boundary.
+#[derive(Debug, Clone)]
+pub struct GridMaps {
+    pub dim: u32,
+    pub size: u32,
+    pub basepoint: u32,
+    /// Vertex images of each map, with grid vertices encoded as in [GridGraph]
+    maps: MapSet,
+    class_of: Vec<usize>,
+    num_classes: usize,
+}
+
+impl GridMaps {
+    pub fn new<G: UGraph>(graph: &G, basepoint: u32, dim: u32, size: u32) -> Self {
+        assert!(basepoint < graph.n());
+        assert!(size >= 1, "grid must have at least one step");
+        let grid = GridGraph::uniform(size, dim);
+        let boundary = (0..grid.n())
+            .filter(|&v| grid.is_boundary(v))
+            .collect::<Vec<_>>();
+        let choices = (0..grid.n())
+            .map(|v| {
+                if grid.is_boundary(v) {
+                    vec![basepoint]
+                } else {
+                    (0..graph.n()).collect()
+                }
+            })
+            .collect::<Vec<_>>();
+        let maps = generate_maps_within(&grid, graph, &choices)
+            .into_iter()
+            .collect();
+
+        // Classes are the components of one-step homotopies fixing the boundary
+        let hom = HomGraph::from_maps_fixing(grid, graph.clone(), maps, boundary);
+        let components = connected_components(&hom);
+        let mut class_of = vec![0; hom.n() as usize];
+        for (class, component) in components.iter().enumerate() {
+            for &i in component {
+                class_of[i as usize] = class;
+            }
+        }
+
+        Self {
+            dim,
+            size,
+            basepoint,
+            maps: hom.into_maps(),
+            class_of,
+            num_classes: components.len(),
+        }
+    }
+
+    pub fn num_classes(&self) -> usize {
+        self.num_classes
+    }
+
+    pub fn maps(&self) -> &MapSet {
+        &self.maps
+    }
+
+    /// The class of the map with vertex images `images`, if it is one of [GridMaps::maps].
+    pub fn class_of(&self, images: &[u32]) -> Option<usize> {
+        self.maps
+            .index_of(images)
+            .map(|i| self.class_of[i as usize])
+    }
+
+    /// One map from each class, in class order.
+    pub fn representatives(&self) -> Vec<&[u32]> {
+        let mut representatives = vec![None; self.num_classes];
+        for (i, &class) in self.class_of.iter().enumerate() {
+            representatives[class].get_or_insert(self.maps.images(i as u32));
+        }
+        representatives.into_iter().map(Option::unwrap).collect()
+    }
+
+    /// Pads the map with vertex images `images` to the grid one size larger, sending the new
+    /// vertices to the basepoint.
+    pub fn pad(&self, images: &[u32]) -> Vec<u32> {
+        let side = (self.size + 1) as usize;
+        let padded_side = side + 1;
+        (0..padded_side.pow(self.dim))
+            .map(|v| {
+                let mut old = 0;
+                let mut stride = 1;
+                let mut rest = v;
+                for _ in 0..self.dim {
+                    let x = rest % padded_side;
+                    if x == side {
+                        return self.basepoint;
+                    }
+                    old += x * stride;
+                    rest /= padded_side;
+                    stride *= side;
+                }
+                images[old]
+            })
+            .collect()
+    }
+
+    /// For each class, the class of its padded representatives among `next`, which must be
+    /// the maps of the grid one size larger.
+    pub fn stabilize(&self, next: &GridMaps) -> Vec<usize> {
+        assert!(next.dim == self.dim && next.size == self.size + 1);
+        assert!(next.basepoint == self.basepoint);
+        self.representatives()
+            .into_iter()
+            .map(|images| {
+                next.class_of(&self.pad(images))
+                    .expect("padded map fixes the boundary")
+            })
+            .collect()
+    }
+}
+
+/// [GridMaps] for each size in `1..=max_size`, with the stabilization from each size to the
+/// next. The classes of `A_n(G, v0)` seen so far are those of the largest size that lie in
+/// the image of the stabilization once it stops growing.
+pub fn grid_homotopy_tower<G: UGraph>(
+    graph: &G,
+    basepoint: u32,
+    dim: u32,
+    max_size: u32,
+) -> (Vec<GridMaps>, Vec<Vec<usize>>) {
+    let levels = (1..=max_size)
+        .map(|size| GridMaps::new(graph, basepoint, dim, size))
+        .collect::<Vec<_>>();
+    let stabilizations = levels
+        .windows(2)
+        .map(|pair| pair[0].stabilize(&pair[1]))
+        .collect();
+    (levels, stabilizations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::extras::c_n_graph;
+
+    #[test]
+    fn test_pad() {
+        let c5 = c_n_graph(5);
+        let level = GridMaps::new(&c5, 0, 2, 2);
+        // The 3x3 grid has a single interior vertex 4
+        assert_eq!(level.maps().len(), 3);
+        let images = [0, 0, 0, 0, 1, 0, 0, 0, 0];
+        assert_eq!(
+            level.pad(&images),
+            vec![0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_loops_in_cycle() {
+        // A_1(C_5) = Z: loops of length m wind at most m / 5 times around C_5
+        let c5 = c_n_graph(5);
+        let (levels, stabilizations) = grid_homotopy_tower(&c5, 0, 1, 7);
+        let counts = levels.iter().map(|l| l.num_classes()).collect::<Vec<_>>();
+        assert_eq!(counts, vec![1, 1, 1, 1, 3, 3, 3]);
+        // Stabilization from I_5 onwards is a bijection on classes
+        for stabilization in &stabilizations[4..] {
+            let mut image = stabilization.clone();
+            image.sort_unstable();
+            assert_eq!(image, vec![0, 1, 2]);
+        }
+
+        // C_4 is contractible
+        let (levels, _) = grid_homotopy_tower(&c_n_graph(4), 0, 1, 6);
+        assert!(levels.iter().all(|l| l.num_classes() == 1));
+    }
+
+    #[test]
+    fn test_a2_of_cycle_is_trivial() {
+        let c5 = c_n_graph(5);
+        let (levels, _) = grid_homotopy_tower(&c5, 0, 2, 3);
+        assert!(levels.iter().all(|l| l.num_classes() == 1));
+    }
+}
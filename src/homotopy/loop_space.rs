@@ -0,0 +1,164 @@
+//! Discrete loop and path spaces as graphs of maps.
+//!
+//! The loops of length `k` based at `v0` are the maps from a path `I_k` or a cycle `C_k` into
+//! `G` sending the endpoints (respectively vertex 0) to `v0`, adjacent when they are one step
+//! apart. Components of the loop graph are based homotopy classes of loops of that length,
+//! which relates `A_(n+1)(G)` to `A_n` of the loop graph.
+
+use std::borrow::Cow;
+
+use crate::graph_maps::hom_graph::{generate_maps_within, HomGraph, MapSet};
+use crate::graph_maps::VertGraphMap;
+use crate::graphs::extras::{c_n_graph, grid_graph};
+use crate::prelude::*;
+
+/// The domain of a based loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopShape {
+    /// The path `I_k` on vertices `0..=k`, with both endpoints at the basepoint
+    Path,
+    /// The cycle `C_k`, with vertex 0 at the basepoint
+    Cycle,
+}
+
+/// A graph whose vertices are maps into a graph, adjacent when they are one step apart.
+#[derive(Debug, Clone)]
+pub struct MapSpace {
+    pub graph: CSRGraph,
+    /// Vertex images of the map each vertex of `graph` stands for
+    pub maps: MapSet,
+}
+
+impl MapSpace {
+    /// The vertex standing for the map with vertex images `images`.
+    pub fn index_of(&self, images: &[u32]) -> Option<u32> {
+        self.maps.index_of(images)
+    }
+
+    /// Every map from `domain` into `graph` sending the `fixed` vertices to `basepoint`.
+    fn based<G: UGraph>(domain: CSRGraph, graph: &G, basepoint: u32, fixed: Vec<u32>) -> Self {
+        let choices = (0..domain.n())
+            .map(|u| {
+                if fixed.contains(&u) {
+                    vec![basepoint]
+                } else {
+                    (0..graph.n()).collect()
+                }
+            })
+            .collect::<Vec<_>>();
+        let maps = generate_maps_within(&domain, graph, &choices)
+            .into_iter()
+            .collect();
+        let hom = HomGraph::from_maps_fixing(domain, graph.clone(), maps, fixed);
+        Self {
+            graph: CSRGraph::from(&hom),
+            maps: hom.into_maps(),
+        }
+    }
+}
+
+/// The graph of loops of the given `length` and `shape` in `graph` based at `basepoint`.
+///
+/// The constant loop is `index_of(&vec![basepoint; n])` for the `n` vertices of the domain.
+pub fn loop_space<G: UGraph>(graph: &G, basepoint: u32, length: u32, shape: LoopShape) -> MapSpace {
+    assert!(basepoint < graph.n());
+    let (domain, fixed) = match shape {
+        LoopShape::Path => {
+            assert!(length >= 1, "a path loop needs at least one step");
+            (grid_graph(length, 1), vec![0, length])
+        }
+        LoopShape::Cycle => {
+            assert!(length >= 3, "cycles have at least 3 vertices");
+            (c_n_graph(length), vec![0])
+        }
+    };
+    MapSpace::based(domain, graph, basepoint, fixed)
+}
+
+/// The graph of paths `I_length -> graph` starting at `basepoint`, with the evaluation at
+/// the far endpoint as a map into `graph`.
+pub fn path_space<G: UGraph>(
+    graph: &G,
+    basepoint: u32,
+    length: u32,
+) -> (MapSpace, VertGraphMap<'static, '_, CSRGraph, G>) {
+    assert!(basepoint < graph.n());
+    assert!(length >= 1, "a path needs at least one step");
+    let space = MapSpace::based(grid_graph(length, 1), graph, basepoint, vec![0]);
+    let endpoints = space
+        .maps
+        .iter()
+        .map(|images| images[length as usize])
+        .collect();
+    // Safety: paths one step apart have endpoints one step apart
+    let evaluation = unsafe {
+        VertGraphMap::new_unchecked(
+            Cow::Owned(space.graph.clone()),
+            Cow::Borrowed(graph),
+            Cow::Owned(endpoints),
+        )
+    };
+    (space, evaluation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_maps::GraphMap;
+    use crate::graphs::connected_components;
+    use crate::graphs::extras::greene_sphere;
+    use crate::homotopy::higher::GridMaps;
+
+    #[test]
+    fn test_loop_space_is_graph() {
+        let c5 = c_n_graph(5);
+        for shape in [LoopShape::Path, LoopShape::Cycle] {
+            let space = loop_space(&c5, 0, 6, shape);
+            assert_eq!(assert_reflexive_symmetric(&space.graph), space.graph);
+            let n = match shape {
+                LoopShape::Path => 7,
+                LoopShape::Cycle => 6,
+            };
+            assert!(space.index_of(&vec![0; n]).is_some());
+        }
+    }
+
+    #[test]
+    fn test_loop_components_match_grid_maps() {
+        // Based path loops of length k are the grid maps I_k^1 fixing the boundary
+        let c5 = c_n_graph(5);
+        for length in 1..=7 {
+            let space = loop_space(&c5, 0, length, LoopShape::Path);
+            let grid_maps = GridMaps::new(&c5, 0, 1, length);
+            assert_eq!(space.maps.len(), grid_maps.maps().len());
+            assert_eq!(
+                connected_components(&space.graph).len(),
+                grid_maps.num_classes()
+            );
+        }
+
+        // Loops in the Greene sphere contract
+        let greene = greene_sphere();
+        let space = loop_space(&greene, 0, 5, LoopShape::Cycle);
+        assert_eq!(connected_components(&space.graph).len(), 1);
+    }
+
+    #[test]
+    fn test_path_space() {
+        let c5 = c_n_graph(5);
+        let (space, evaluation) = path_space(&c5, 0, 3);
+        assert_eq!(connected_components(&space.graph).len(), 1);
+        let mut workspace = vec![0; space.graph.n() as usize];
+        VertGraphMap::try_from(
+            Cow::Borrowed(&space.graph),
+            Cow::Borrowed(&c5),
+            evaluation.mapped_vertices(),
+            &mut workspace,
+        )
+        .expect("evaluation should be a graph map");
+        let mut endpoints = evaluation.mapped_vertices().collect::<Vec<_>>();
+        endpoints.sort_unstable();
+        endpoints.dedup();
+        assert_eq!(endpoints, [0, 1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod fundamental_group;
+pub mod higher;
+pub mod loop_space;
@@ -1,5 +1,9 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
 pub mod graph_maps;
 pub mod graphs;
+pub mod homology;
+pub mod homotopy;
 pub mod shape;
 
 pub mod prelude {
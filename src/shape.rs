@@ -73,6 +73,30 @@ where
     }
 }
 
+impl<const N: u32> core::ops::Sub<Const<N>> for u32 {
+    type Output = u32;
+    fn sub(self, _: Const<N>) -> Self::Output {
+        self.size() - N
+    }
+}
+impl<const N: u32> core::ops::Sub<u32> for Const<N> {
+    type Output = u32;
+    fn sub(self, rhs: u32) -> Self::Output {
+        N - rhs.size()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const N: u32, const M: u32> core::ops::Sub<Const<N>> for Const<M>
+where
+    Const<{ M - N }>: Sized,
+{
+    type Output = Const<{ M - N }>;
+    fn sub(self, _: Const<N>) -> Self::Output {
+        Const
+    }
+}
+
 impl<const N: u32> core::ops::Mul<Const<N>> for u32 {
     type Output = u32;
     fn mul(self, _: Const<N>) -> Self::Output {
@@ -120,3 +144,60 @@ where
         Const
     }
 }
+
+/// A [Dim] whose predecessor and successor dimensions have known types,
+/// e.g. the faces and gluings of a cube.
+///
+/// Runtime dimensions stay `u32`. Without the `nightly` feature, [Const] dimensions
+/// step down to `u32`; with it they step to `Const<{ N - 1 }>` and `Const<{ N + 1 }>`.
+pub trait StepDim: Dim {
+    type Prev: Dim;
+    type Next: Dim;
+    fn prev(&self) -> Self::Prev;
+    fn next(&self) -> Self::Next;
+}
+
+impl StepDim for u32 {
+    type Prev = u32;
+    type Next = u32;
+    #[inline(always)]
+    fn prev(&self) -> u32 {
+        *self - Const::<1>
+    }
+    #[inline(always)]
+    fn next(&self) -> u32 {
+        *self + Const::<1>
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<const N: u32> StepDim for Const<N> {
+    type Prev = u32;
+    type Next = u32;
+    #[inline(always)]
+    fn prev(&self) -> u32 {
+        *self - 1
+    }
+    #[inline(always)]
+    fn next(&self) -> u32 {
+        *self + 1
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const N: u32> StepDim for Const<N>
+where
+    Const<{ N - 1 }>: Sized,
+    Const<{ N + 1 }>: Sized,
+{
+    type Prev = <Const<N> as core::ops::Sub<Const<1>>>::Output;
+    type Next = <Const<N> as core::ops::Add<Const<1>>>::Output;
+    #[inline(always)]
+    fn prev(&self) -> Self::Prev {
+        *self - Const::<1>
+    }
+    #[inline(always)]
+    fn next(&self) -> Self::Next {
+        *self + Const::<1>
+    }
+}